@@ -0,0 +1,239 @@
+//! `aoc run --animate`: draw the frames a solver emits as a terminal animation.
+//!
+//! While it plays, Enter pauses. When paused, Enter steps one frame, `c`
+//! carries on playing and `+`/`-` change the speed. `q` stops drawing and lets
+//! the solver finish.
+
+use std::collections::HashSet;
+use std::io::{self, BufRead, Write};
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError};
+use std::thread;
+use std::time::Duration;
+
+use aoc_grid::frames::Frame;
+
+const CLEAR: &str = "\x1b[2J";
+const HOME: &str = "\x1b[H";
+const CLEAR_LINE: &str = "\x1b[K";
+const RESET: &str = "\x1b[0m";
+const HIGHLIGHT: &str = "\x1b[1;30;43m";
+const MARK: &str = "\x1b[1;30;46m";
+const DIM: &str = "\x1b[2m";
+const HIDE_CURSOR: &str = "\x1b[?25l";
+const SHOW_CURSOR: &str = "\x1b[?25h";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Control {
+    /// Enter: pause, or step when paused
+    Pause,
+    Continue,
+    Quit,
+    Faster,
+    Slower,
+}
+
+impl Control {
+    fn parse(line: &str) -> Option<Self> {
+        match line.trim() {
+            "" => Some(Control::Pause),
+            "c" => Some(Control::Continue),
+            "q" => Some(Control::Quit),
+            "+" => Some(Control::Faster),
+            "-" => Some(Control::Slower),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Mode {
+    Playing,
+    Paused,
+    Stopped,
+}
+
+pub struct Player<W: Write> {
+    out: W,
+    controls: Receiver<Control>,
+    delay: Duration,
+    mode: Mode,
+    shown: usize,
+}
+
+impl Player<io::Stdout> {
+    /// Draw on the terminal at `fps` frames a second, reading controls from stdin
+    pub fn terminal(fps: f64, paused: bool) -> Self {
+        let (send, controls) = mpsc::channel();
+        thread::spawn(move || {
+            for line in io::stdin().lock().lines() {
+                let Ok(line) = line else { break };
+                if let Some(control) = Control::parse(&line) {
+                    if send.send(control).is_err() {
+                        break;
+                    }
+                }
+            }
+        });
+        Self::new(io::stdout(), controls, fps, paused)
+    }
+}
+
+impl<W: Write> Player<W> {
+    fn new(out: W, controls: Receiver<Control>, fps: f64, paused: bool) -> Self {
+        Self {
+            out,
+            controls,
+            delay: Duration::from_secs_f64(1.0 / fps.max(0.1)),
+            mode: if paused { Mode::Paused } else { Mode::Playing },
+            shown: 0,
+        }
+    }
+
+    /// Draw `frame`, then wait as long as the speed and controls say
+    pub fn show(&mut self, frame: &Frame) {
+        if self.mode == Mode::Stopped {
+            return;
+        }
+        if self.shown == 0 {
+            let _ = write!(self.out, "{HIDE_CURSOR}{CLEAR}");
+        }
+        self.shown += 1;
+        let status = format!(
+            "frame {} at {:.0} fps: Enter pauses and steps, c continues, +/- change speed, q stops",
+            self.shown,
+            1.0 / self.delay.as_secs_f64()
+        );
+        let _ = write!(self.out, "{HOME}{}", render(frame, &status));
+        let _ = self.out.flush();
+        self.wait();
+    }
+
+    fn wait(&mut self) {
+        loop {
+            let control = match self.mode {
+                Mode::Playing => match self.controls.recv_timeout(self.delay) {
+                    Ok(control) => control,
+                    Err(RecvTimeoutError::Timeout | RecvTimeoutError::Disconnected) => return,
+                },
+                Mode::Paused => match self.controls.recv() {
+                    Ok(control) => control,
+                    // nobody left to unpause it
+                    Err(_) => Control::Continue,
+                },
+                Mode::Stopped => return,
+            };
+            match control {
+                Control::Pause if self.mode == Mode::Paused => return,
+                Control::Pause => self.mode = Mode::Paused,
+                Control::Continue => {
+                    self.mode = Mode::Playing;
+                    return;
+                }
+                Control::Quit => {
+                    self.mode = Mode::Stopped;
+                    return;
+                }
+                Control::Faster => self.delay /= 2,
+                Control::Slower => self.delay *= 2,
+            }
+            if self.mode == Mode::Playing {
+                return;
+            }
+        }
+    }
+
+    /// Put the terminal back the way it was
+    pub fn finish(&mut self) {
+        if self.shown > 0 {
+            let _ = writeln!(self.out, "{SHOW_CURSOR}");
+            let _ = self.out.flush();
+            self.shown = 0;
+        }
+    }
+}
+
+/// The frame's grid, highlighted and marked cells in color, then its caption
+/// and `status`. Lines are left to the pictures `--render` saves.
+fn render(frame: &Frame, status: &str) -> String {
+    let highlights = frame.highlights.iter().collect::<HashSet<_>>();
+    let marks = frame.marks.iter().collect::<HashSet<_>>();
+    let mut out = String::new();
+    for (coord, &cell) in frame.cells.iter() {
+        if highlights.contains(&coord) {
+            out.push_str(HIGHLIGHT);
+            out.push(cell);
+            out.push_str(RESET);
+        } else if marks.contains(&coord) {
+            out.push_str(MARK);
+            out.push(cell);
+            out.push_str(RESET);
+        } else if cell == '.' {
+            out.push_str(DIM);
+            out.push(cell);
+            out.push_str(RESET);
+        } else {
+            out.push(cell);
+        }
+        if coord.col + 1 == frame.cells.width() {
+            out.push_str(CLEAR_LINE);
+            out.push('\n');
+        }
+    }
+    for line in [frame.caption.as_str(), status] {
+        out.push_str(line);
+        out.push_str(CLEAR_LINE);
+        out.push('\n');
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_grid::{Coord, Grid};
+
+    use super::*;
+
+    fn frame() -> Frame {
+        let grid = Grid::parse("#.\nO#\n", Some, "anything").unwrap();
+        Frame::new(&grid)
+            .highlight([Coord::new(1, 0)])
+            .caption("rolled")
+    }
+
+    #[test]
+    fn test_render() {
+        assert_eq!(
+            render(&frame(), "frame 1"),
+            "#\x1b[2m.\x1b[0m\x1b[K\n\x1b[1;30;43mO\x1b[0m#\x1b[K\nrolled\x1b[K\nframe 1\x1b[K\n"
+        );
+    }
+
+    #[test]
+    fn test_controls() {
+        let (send, controls) = mpsc::channel();
+        let mut player = Player::new(Vec::new(), controls, 1000.0, true);
+        for control in ["+", "", "c", "q"] {
+            send.send(Control::parse(control).unwrap()).unwrap();
+        }
+        // paused: speeds up, then steps one frame
+        player.show(&frame());
+        assert_eq!(
+            (player.mode, player.delay),
+            (Mode::Paused, Duration::from_micros(500))
+        );
+        // steps again, this time on "c"
+        player.show(&frame());
+        assert_eq!(player.mode, Mode::Playing);
+        // playing: "q" arrives while waiting after this frame
+        player.show(&frame());
+        assert_eq!(player.mode, Mode::Stopped);
+        player.show(&frame());
+        assert_eq!(player.shown, 3);
+
+        player.finish();
+        let drawn = String::from_utf8(player.out).unwrap();
+        assert!(drawn.starts_with(HIDE_CURSOR));
+        assert!(drawn.ends_with(&format!("{SHOW_CURSOR}\n")));
+        assert_eq!(drawn.matches("rolled").count(), 3);
+    }
+}
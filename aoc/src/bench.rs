@@ -0,0 +1,242 @@
+//! `aoc bench`: time the parse and solve phases of a day over many runs, and
+//! compare the results with a baseline saved by an earlier run.
+
+use std::fmt;
+use std::hint::black_box;
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+use anyhow::{Context, Result};
+use aoc_common::{ParseError, Part, Solution};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy)]
+pub struct BenchConfig {
+    pub warmup: usize,
+    pub runs: usize,
+}
+
+/// Summary statistics of one phase, in nanoseconds
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Stats {
+    pub min: f64,
+    pub median: f64,
+    pub mean: f64,
+    pub stddev: f64,
+}
+
+impl Stats {
+    fn from_samples(samples: &[Duration]) -> Self {
+        assert!(!samples.is_empty(), "no samples to summarize");
+        let mut nanos = samples
+            .iter()
+            .map(|d| d.as_nanos() as f64)
+            .collect::<Vec<_>>();
+        nanos.sort_by(f64::total_cmp);
+        let n = nanos.len();
+        let median = match n % 2 {
+            0 => (nanos[n / 2 - 1] + nanos[n / 2]) / 2.0,
+            _ => nanos[n / 2],
+        };
+        let mean = nanos.iter().sum::<f64>() / n as f64;
+        let variance = nanos.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / n as f64;
+        Self {
+            min: nanos[0],
+            median,
+            mean,
+            stddev: variance.sqrt(),
+        }
+    }
+}
+
+impl fmt::Display for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "min {:>10}  median {:>10}  mean {:>10}  stddev {:>10}",
+            format_nanos(self.min),
+            format_nanos(self.median),
+            format_nanos(self.mean),
+            format_nanos(self.stddev)
+        )
+    }
+}
+
+fn format_nanos(nanos: f64) -> String {
+    format!("{:.2?}", Duration::from_nanos(nanos.round() as u64))
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Report {
+    pub day: u8,
+    pub part: Part,
+    pub runs: usize,
+    pub parse: Stats,
+    pub solve: Stats,
+}
+
+impl Report {
+    pub fn print(&self, baseline: Option<&Report>, threshold: f64) {
+        println!(
+            "Day {:02} part {} ({} runs)",
+            self.day, self.part, self.runs
+        );
+        for (phase, stats, base) in [
+            ("parse", &self.parse, baseline.map(|b| &b.parse)),
+            ("solve", &self.solve, baseline.map(|b| &b.solve)),
+        ] {
+            println!("  {phase}  {stats}");
+            if let Some(base) = base {
+                let change = (stats.median - base.median) / base.median * 100.0;
+                println!(
+                    "         baseline median {} -> {} ({change:+.1}%): {}",
+                    format_nanos(base.median),
+                    format_nanos(stats.median),
+                    Verdict::compare(base, stats, threshold)
+                );
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verdict {
+    Regression,
+    Improvement,
+    NoChange,
+}
+
+impl Verdict {
+    /// A change counts when the medians differ by more than `threshold` percent
+    /// and by more than the noise in either measurement.
+    pub fn compare(baseline: &Stats, current: &Stats, threshold: f64) -> Self {
+        let diff = current.median - baseline.median;
+        let noise = baseline.stddev.max(current.stddev);
+        if diff.abs() <= noise || diff.abs() <= baseline.median * threshold / 100.0 {
+            Verdict::NoChange
+        } else if diff > 0.0 {
+            Verdict::Regression
+        } else {
+            Verdict::Improvement
+        }
+    }
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::Regression => write!(f, "REGRESSION"),
+            Verdict::Improvement => write!(f, "improvement"),
+            Verdict::NoChange => write!(f, "no change"),
+        }
+    }
+}
+
+/// Time `config.runs` parses and solves of one part, after `config.warmup` untimed runs
+pub fn bench_solution<S: Solution>(
+    day: u8,
+    input: &str,
+    part: Part,
+    config: &BenchConfig,
+) -> Result<Report, ParseError> {
+    let mut parse_times = Vec::with_capacity(config.runs);
+    let mut solve_times = Vec::with_capacity(config.runs);
+    for run in 0..config.warmup + config.runs {
+        let t0 = Instant::now();
+        let parsed = S::parse(black_box(input))?;
+        let t1 = Instant::now();
+        let answer = match part {
+            Part::A => S::part_a(&parsed),
+            Part::B => S::part_b(&parsed),
+        };
+        let t2 = Instant::now();
+        black_box(answer);
+        if run >= config.warmup {
+            parse_times.push(t1 - t0);
+            solve_times.push(t2 - t1);
+        }
+    }
+    Ok(Report {
+        day,
+        part,
+        runs: config.runs,
+        parse: Stats::from_samples(&parse_times),
+        solve: Stats::from_samples(&solve_times),
+    })
+}
+
+pub fn save(path: &Path, reports: &[Report]) -> Result<()> {
+    let json = serde_json::to_string_pretty(reports)?;
+    std::fs::write(path, json).with_context(|| format!("writing {}", path.display()))
+}
+
+pub fn load(path: &Path) -> Result<Vec<Report>> {
+    let json =
+        std::fs::read_to_string(path).with_context(|| format!("reading {}", path.display()))?;
+    serde_json::from_str(&json).with_context(|| format!("parsing {}", path.display()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stats(median: f64, stddev: f64) -> Stats {
+        Stats {
+            min: median,
+            median,
+            mean: median,
+            stddev,
+        }
+    }
+
+    #[test]
+    fn test_stats() {
+        let samples = [4, 1, 3, 2].map(Duration::from_nanos);
+        let stats = Stats::from_samples(&samples);
+        assert_eq!(stats.min, 1.0);
+        assert_eq!(stats.median, 2.5);
+        assert_eq!(stats.mean, 2.5);
+        assert!((stats.stddev - 1.25f64.sqrt()).abs() < 1e-9);
+
+        let stats = Stats::from_samples(&[7, 1, 4].map(Duration::from_nanos));
+        assert_eq!(stats.median, 4.0);
+    }
+
+    #[test]
+    fn test_verdict() {
+        let base = stats(1000.0, 10.0);
+        assert_eq!(
+            Verdict::compare(&base, &stats(1200.0, 10.0), 5.0),
+            Verdict::Regression
+        );
+        assert_eq!(
+            Verdict::compare(&base, &stats(800.0, 10.0), 5.0),
+            Verdict::Improvement
+        );
+        // within the threshold
+        assert_eq!(
+            Verdict::compare(&base, &stats(1030.0, 10.0), 5.0),
+            Verdict::NoChange
+        );
+        // outside the threshold but within the noise
+        assert_eq!(
+            Verdict::compare(&base, &stats(1200.0, 300.0), 5.0),
+            Verdict::NoChange
+        );
+    }
+
+    #[test]
+    fn test_json_roundtrip() {
+        let report = Report {
+            day: 14,
+            part: Part::B,
+            runs: 3,
+            parse: stats(10.0, 1.0),
+            solve: stats(2000.0, 30.0),
+        };
+        let json = serde_json::to_string(std::slice::from_ref(&report)).unwrap();
+        let loaded: Vec<Report> = serde_json::from_str(&json).unwrap();
+        assert_eq!(loaded[0].part, report.part);
+        assert_eq!(loaded[0].solve, report.solve);
+    }
+}
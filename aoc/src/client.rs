@@ -0,0 +1,225 @@
+//! HTTP access to adventofcode.com, shared by `aoc fetch` and `aoc submit`.
+//!
+//! Settings come from the environment (`AOC_SESSION`, `AOC_BASE_URL`,
+//! `AOC_YEAR`) and fall back to `.aoc.toml` in the workspace root:
+//!
+//! ```toml
+//! session = "53616c7465645f5f..."
+//! base_url = "https://adventofcode.com"
+//! year = 2023
+//! contact = "you@example.com"
+//! ```
+
+use std::env;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use anyhow::{anyhow, Context, Result};
+use aoc_common::Part;
+use serde::Deserialize;
+
+pub const CONFIG_FILE: &str = ".aoc.toml";
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+/// The event the puzzles in this workspace belong to
+pub const DEFAULT_YEAR: u16 = 2023;
+
+const USER_AGENT: &str = concat!("aoc-runner/", env!("CARGO_PKG_VERSION"));
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Config {
+    pub session: Option<String>,
+    pub base_url: Option<String>,
+    pub year: Option<u16>,
+    /// Added to the User-Agent, so the site operators can reach whoever runs the tool
+    pub contact: Option<String>,
+}
+
+impl Config {
+    /// Read `.aoc.toml` from `root`, then let the environment override it
+    pub fn load(root: &Path) -> Result<Self> {
+        let path = root.join(CONFIG_FILE);
+        let mut config = match fs::read_to_string(&path) {
+            Ok(text) => {
+                toml::from_str(&text).with_context(|| format!("parsing {}", path.display()))?
+            }
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Config::default(),
+            Err(err) => return Err(err).with_context(|| format!("reading {}", path.display())),
+        };
+        if let Ok(session) = env::var("AOC_SESSION") {
+            config.session = Some(session);
+        }
+        if let Ok(base_url) = env::var("AOC_BASE_URL") {
+            config.base_url = Some(base_url);
+        }
+        if let Ok(year) = env::var("AOC_YEAR") {
+            config.year = Some(year.parse().context("AOC_YEAR is not a year")?);
+        }
+        Ok(config)
+    }
+
+    pub fn year(&self) -> u16 {
+        self.year.unwrap_or(DEFAULT_YEAR)
+    }
+
+    fn user_agent(&self) -> String {
+        match &self.contact {
+            Some(contact) => format!("{USER_AGENT} ({contact})"),
+            None => USER_AGENT.to_string(),
+        }
+    }
+}
+
+pub struct Client {
+    agent: ureq::Agent,
+    base_url: String,
+    session: String,
+}
+
+impl Client {
+    pub fn new(config: &Config) -> Result<Self> {
+        let session = config.session.clone().ok_or_else(|| {
+            anyhow!("no session token, set AOC_SESSION or add session to {CONFIG_FILE}")
+        })?;
+        let base_url = config
+            .base_url
+            .as_deref()
+            .unwrap_or(DEFAULT_BASE_URL)
+            .trim_end_matches('/')
+            .to_string();
+        Ok(Self {
+            agent: ureq::AgentBuilder::new()
+                .user_agent(&config.user_agent())
+                .build(),
+            base_url,
+            session,
+        })
+    }
+
+    fn url(&self, year: u16, day: u8, path: &str) -> String {
+        format!("{}/{year}/day/{day}{path}", self.base_url)
+    }
+
+    fn cookie(&self) -> String {
+        format!("session={}", self.session)
+    }
+
+    /// The personal puzzle input for a day
+    pub fn input(&self, year: u16, day: u8) -> Result<String> {
+        let url = self.url(year, day, "/input");
+        let response = self
+            .agent
+            .get(&url)
+            .set("Cookie", &self.cookie())
+            .call()
+            .map_err(|err| request_error("GET", &url, err))?;
+        response
+            .into_string()
+            .with_context(|| format!("reading response from {url}"))
+    }
+
+    /// Post an answer and return the HTML page the site responds with
+    pub fn submit(&self, year: u16, day: u8, part: Part, answer: &str) -> Result<String> {
+        let url = self.url(year, day, "/answer");
+        let level = match part {
+            Part::A => "1",
+            Part::B => "2",
+        };
+        let response = self
+            .agent
+            .post(&url)
+            .set("Cookie", &self.cookie())
+            .send_form(&[("level", level), ("answer", answer)])
+            .map_err(|err| request_error("POST", &url, err))?;
+        response
+            .into_string()
+            .with_context(|| format!("reading response from {url}"))
+    }
+}
+
+fn request_error(method: &str, url: &str, err: ureq::Error) -> anyhow::Error {
+    match err {
+        ureq::Error::Status(code @ (400 | 401 | 403), _) => anyhow!(
+            "{method} {url} returned {code}, the session token is probably missing or expired"
+        ),
+        ureq::Error::Status(404, _) => {
+            anyhow!("{method} {url} returned 404, the puzzle isn't out yet")
+        }
+        ureq::Error::Status(code, _) => anyhow!("{method} {url} returned {code}"),
+        ureq::Error::Transport(transport) => anyhow!("{method} {url} failed: {transport}"),
+    }
+}
+
+/// A stand-in for adventofcode.com that answers each request with a canned
+/// response and hands the raw requests back for inspection.
+#[cfg(test)]
+pub mod mock {
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+    use std::sync::mpsc;
+    use std::thread;
+
+    use super::Config;
+
+    pub struct Server {
+        pub base_url: String,
+        requests: mpsc::Receiver<String>,
+    }
+
+    impl Server {
+        /// Serve one `(status, body)` response per incoming request, in order
+        pub fn start(responses: Vec<(u16, String)>) -> Self {
+            let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+            let base_url = format!("http://{}", listener.local_addr().unwrap());
+            let (tx, requests) = mpsc::channel();
+            thread::spawn(move || {
+                for (status, body) in responses {
+                    let (stream, _) = listener.accept().unwrap();
+                    let mut reader = BufReader::new(stream);
+                    let mut request = String::new();
+                    let mut content_length = 0;
+                    loop {
+                        let mut line = String::new();
+                        reader.read_line(&mut line).unwrap();
+                        if let Some(len) = line.to_ascii_lowercase().strip_prefix("content-length:")
+                        {
+                            content_length = len.trim().parse().unwrap();
+                        }
+                        request.push_str(&line);
+                        if line == "\r\n" || line.is_empty() {
+                            break;
+                        }
+                    }
+                    let mut payload = vec![0; content_length];
+                    reader.read_exact(&mut payload).unwrap();
+                    request.push_str(&String::from_utf8(payload).unwrap());
+                    tx.send(request).unwrap();
+
+                    let mut stream = reader.into_inner();
+                    write!(
+                        stream,
+                        "HTTP/1.1 {status} X\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                        body.len()
+                    )
+                    .unwrap();
+                }
+            });
+            Self { base_url, requests }
+        }
+
+        pub fn config(&self) -> Config {
+            Config {
+                session: Some("abc123".to_string()),
+                base_url: Some(self.base_url.clone()),
+                year: Some(2023),
+                contact: Some("test@example.com".to_string()),
+            }
+        }
+
+        /// The next request the server received
+        pub fn request(&self) -> String {
+            self.requests.recv().unwrap()
+        }
+    }
+}
@@ -0,0 +1,115 @@
+//! Registry of every solved day, in the order they are run by `aoc run all`.
+
+use std::path::PathBuf;
+
+use anyhow::{anyhow, Result};
+use aoc_common::gen::Seeded;
+use aoc_common::{Answer, ParseError, Part, Solution};
+
+use crate::bench::{self, BenchConfig, Report};
+
+/// Shows how a part's answer comes about, as a day's `explain` function does
+pub type Explain = fn(&str, Part) -> Result<String, ParseError>;
+
+/// Converts a value from one of a day's categories to another, as a day's `convert`
+/// function does. The inner error is for categories the input doesn't have.
+pub type Convert = fn(&str, &str, &str, i64) -> Result<Result<Vec<i64>, String>, ParseError>;
+
+pub struct Day {
+    pub number: u8,
+    pub solve: fn(&str, Part) -> Result<Answer, ParseError>,
+    pub bench: fn(u8, &str, Part, &BenchConfig) -> Result<Report, ParseError>,
+    /// A random input of roughly `size`, see the day's `gen` module
+    pub generate: fn(&mut Seeded, usize) -> String,
+    /// For days that can explain their answers, with `aoc run --explain`
+    pub explain: Option<Explain>,
+    /// For days with categories of values, with `aoc run --from --to --value`
+    pub convert: Option<Convert>,
+}
+
+impl Day {
+    /// The puzzle_NN crate directory, which holds this day's inputs
+    pub fn dir(&self) -> PathBuf {
+        workspace_root().join(format!("puzzle_{:02}", self.number))
+    }
+
+    /// Solve one part of `input`, read from `source`. A parse error becomes a
+    /// diagnostic quoting the offending line.
+    pub fn run(&self, input: &str, source: &str, part: Part) -> Result<Answer> {
+        (self.solve)(input, part).map_err(|err| parse_failure(source, input, &err))
+    }
+
+    /// The day's explanation of one part of `input`, see [`Day::explain`]
+    pub fn explain(&self, input: &str, source: &str, part: Part) -> Result<String> {
+        let explain = self
+            .explain
+            .ok_or_else(|| anyhow!("day {} has nothing to explain", self.number))?;
+        explain(input, part).map_err(|err| parse_failure(source, input, &err))
+    }
+
+    /// What `value` in category `from` of `input` is in category `to`, see [`Day::convert`]
+    pub fn convert(
+        &self,
+        input: &str,
+        source: &str,
+        (from, to): (&str, &str),
+        value: i64,
+    ) -> Result<Vec<i64>> {
+        let convert = self
+            .convert
+            .ok_or_else(|| anyhow!("day {} has no categories to convert", self.number))?;
+        convert(input, from, to, value)
+            .map_err(|err| parse_failure(source, input, &err))?
+            .map_err(|err| anyhow!(err))
+    }
+}
+
+pub fn parse_failure(source: &str, input: &str, err: &ParseError) -> anyhow::Error {
+    anyhow!("could not parse {source}\n{}", err.render(input))
+}
+
+pub fn workspace_root() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("aoc crate lives inside the workspace")
+        .to_path_buf()
+}
+
+/// `day!(5, puzzle_05, explain)` registers puzzle_05, along with the optional
+/// functions it has, named after the `Day` fields they fill in
+macro_rules! day {
+    ($number:literal, $krate:ident $(, $extra:ident)*) => {{
+        #[allow(unused_mut)]
+        let mut day = Day {
+            number: $number,
+            solve: <$krate::Puzzle as Solution>::solve,
+            bench: bench::bench_solution::<$krate::Puzzle>,
+            generate: $krate::gen::generate::<Seeded>,
+            explain: None,
+            convert: None,
+        };
+        $(day.$extra = Some($krate::$extra);)*
+        day
+    }};
+}
+
+pub static DAYS: &[Day] = &[
+    day!(1, puzzle_01),
+    day!(2, puzzle_02),
+    day!(3, puzzle_03),
+    day!(4, puzzle_04),
+    day!(5, puzzle_05, explain, convert),
+    day!(6, puzzle_06),
+    day!(7, puzzle_07),
+    day!(8, puzzle_08),
+    day!(9, puzzle_09),
+    day!(10, puzzle_10),
+    day!(11, puzzle_11),
+    day!(12, puzzle_12, explain),
+    day!(13, puzzle_13),
+    day!(14, puzzle_14),
+];
+
+pub fn find(number: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|day| day.number == number)
+}
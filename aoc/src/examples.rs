@@ -0,0 +1,242 @@
+//! `aoc examples`: pull the example inputs and their expected answers out of a
+//! saved puzzle description page into puzzle_NN/examples.
+//!
+//! Each part of the puzzle is an `<article>`. Its example input is the first
+//! `<pre><code>` block in it, and its expected answer is the last emphasized
+//! `<code><em>` in it. Part two often reuses the example of part one, so a part
+//! without a block of its own gets attached to the previous part's example.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::{anyhow, Context, Result};
+use aoc_common::examples::{self, EXAMPLES_DIR};
+use aoc_common::known::PartAnswers;
+use aoc_common::{Answer, Part};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Extracted {
+    pub input: String,
+    pub answers: PartAnswers,
+}
+
+pub fn extract(html: &str) -> Vec<Extracted> {
+    let mut extracted: Vec<Extracted> = Vec::new();
+    for (article, part) in articles(html).into_iter().zip([Part::A, Part::B]) {
+        let Some(answer) = last_between(article, "<code><em>", "</em></code>") else {
+            continue;
+        };
+        let answer = unescape(&strip_tags(answer));
+        let answer = match answer.parse::<i64>() {
+            Ok(n) => Answer::from(n),
+            Err(_) => Answer::from(answer),
+        };
+        let example = match first_between(article, "<pre><code>", "</code></pre>") {
+            Some(block) => {
+                let input = unescape(&strip_tags(block));
+                match extracted.iter().position(|e| e.input == input) {
+                    Some(i) => i,
+                    None => {
+                        extracted.push(Extracted {
+                            input,
+                            answers: PartAnswers::default(),
+                        });
+                        extracted.len() - 1
+                    }
+                }
+            }
+            None if !extracted.is_empty() => extracted.len() - 1,
+            None => continue,
+        };
+        extracted[example].answers.set(part, answer);
+    }
+    extracted
+}
+
+fn articles(html: &str) -> Vec<&str> {
+    let mut articles = Vec::new();
+    let mut rest = html;
+    while let Some(start) = rest.find("<article") {
+        let Some(end) = rest[start..].find("</article>") else {
+            break;
+        };
+        articles.push(&rest[start..start + end]);
+        rest = &rest[start + end..];
+    }
+    articles
+}
+
+fn first_between<'a>(s: &'a str, open: &str, close: &str) -> Option<&'a str> {
+    let start = s.find(open)? + open.len();
+    let len = s[start..].find(close)?;
+    Some(&s[start..start + len])
+}
+
+fn last_between<'a>(s: &'a str, open: &str, close: &str) -> Option<&'a str> {
+    let start = s.rfind(open)? + open.len();
+    let len = s[start..].find(close)?;
+    Some(&s[start..start + len])
+}
+
+/// Example blocks highlight parts of the input with `<em>`, drop those tags
+fn strip_tags(s: &str) -> String {
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in s.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' if in_tag => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    text
+}
+
+fn unescape(s: &str) -> String {
+    s.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&#x27;", "'")
+        .replace("&amp;", "&")
+}
+
+/// Write the examples into `day_dir/examples`, numbered after the ones already
+/// there. An example that is already saved only gets its answers updated, so a
+/// page saved again after solving part one adds the part two answer.
+pub fn save(day_dir: &Path, extracted: &[Extracted]) -> Result<Vec<PathBuf>> {
+    let dir = day_dir.join(EXAMPLES_DIR);
+    fs::create_dir_all(&dir).with_context(|| format!("creating {}", dir.display()))?;
+
+    let mut existing = Vec::new();
+    for entry in fs::read_dir(&dir)? {
+        let path = entry?.path();
+        if path.extension().is_some_and(|ext| ext == "txt") {
+            existing.push((fs::read_to_string(&path)?, path));
+        }
+    }
+    let mut next = examples::next_number(day_dir)?;
+
+    let mut written = Vec::new();
+    for example in extracted {
+        let input_path = match existing.iter().find(|(input, _)| *input == example.input) {
+            Some((_, path)) => path.clone(),
+            None => {
+                let path = examples::input_path(day_dir, next);
+                next += 1;
+                fs::write(&path, &example.input)?;
+                path
+            }
+        };
+        let answers_path = examples::answers_path(&input_path);
+        let mut answers = match answers_path.exists() {
+            true => examples::load_answers(&answers_path)?,
+            false => PartAnswers::default(),
+        };
+        for part in [Part::A, Part::B] {
+            if let Some(answer) = example.answers.get(part) {
+                answers.set(part, answer.clone());
+            }
+        }
+        examples::save_answers(&answers_path, &answers)?;
+        written.push(input_path);
+    }
+    Ok(written)
+}
+
+pub fn extract_to_day(day_dir: &Path, page: &Path) -> Result<Vec<PathBuf>> {
+    let html = fs::read_to_string(page).with_context(|| format!("reading {}", page.display()))?;
+    let extracted = extract(&html);
+    if extracted.is_empty() {
+        return Err(anyhow!(
+            "no example with an answer found in {}",
+            page.display()
+        ));
+    }
+    save(day_dir, &extracted)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PAGE: &str = r#"<html><body><main>
+<article class="day-desc"><h2>--- Day 9: Mirage Maintenance ---</h2>
+<p>For example:</p>
+<pre><code>0 3 6 9 12 15
+1 3 6 10 15 21
+10 13 16 21 30 45
+</code></pre>
+<p>Adding the new values gives:</p>
+<pre><code><em>0   3   6   9  12  15  18</em>
+  3   3   3   3   3   3
+</code></pre>
+<p>The sum of these extrapolated values is <code><em>114</em></code>.</p>
+</article>
+<p>Your puzzle answer was <code>1234</code>.</p>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2>
+<p>Adding the new values on the left side gives <code><em>2</em></code>.</p>
+</article>
+</main></body></html>"#;
+
+    #[test]
+    fn test_extract() {
+        let extracted = extract(PAGE);
+        assert_eq!(extracted.len(), 1);
+        assert_eq!(
+            extracted[0].input,
+            "0 3 6 9 12 15\n1 3 6 10 15 21\n10 13 16 21 30 45\n"
+        );
+        assert_eq!(extracted[0].answers.get(Part::A), Some(&Answer::from(114)));
+        assert_eq!(extracted[0].answers.get(Part::B), Some(&Answer::from(2)));
+    }
+
+    #[test]
+    fn test_extract_separate_examples() {
+        let page = r#"<article><pre><code>a &lt; b &amp;&amp; <em>c</em>
+</code></pre><p>gives <code><em>142</em></code></p></article>
+<article><pre><code>two1nine
+</code></pre><p>gives <code><em>ABC</em></code></p></article>"#;
+        let extracted = extract(page);
+        assert_eq!(extracted.len(), 2);
+        assert_eq!(extracted[0].input, "a < b && c\n");
+        assert_eq!(extracted[0].answers.get(Part::A), Some(&Answer::from(142)));
+        assert_eq!(extracted[0].answers.get(Part::B), None);
+        assert_eq!(
+            extracted[1].answers.get(Part::B),
+            Some(&Answer::from("ABC"))
+        );
+    }
+
+    #[test]
+    fn test_save() {
+        let dir = std::env::temp_dir().join(format!("aoc-extract-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        let mut first = extract(PAGE);
+        first[0].answers.b = None;
+        save(&dir, &first).unwrap();
+
+        // the full page again, plus an example that's new
+        let mut second = extract(PAGE);
+        second.push(Extracted {
+            input: "1 2 3\n".to_string(),
+            answers: PartAnswers {
+                a: Some(Answer::from(4)),
+                b: None,
+            },
+        });
+        let written = save(&dir, &second).unwrap();
+        let examples_dir = dir.join(EXAMPLES_DIR);
+        assert_eq!(
+            written,
+            [examples_dir.join("01.txt"), examples_dir.join("02.txt")]
+        );
+
+        let examples = examples::list(&dir).unwrap();
+        assert_eq!(examples.len(), 2);
+        assert_eq!(examples[0].answers.get(Part::B), Some(&Answer::from(2)));
+        assert_eq!(examples[1].answers.get(Part::A), Some(&Answer::from(4)));
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
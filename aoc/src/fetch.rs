@@ -0,0 +1,75 @@
+//! `aoc fetch`: download a day's puzzle input into puzzle_NN/input.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::{anyhow, Context, Result};
+
+use crate::client::Client;
+
+/// Download the input into `dir/input`, unless it is already there.
+/// An empty input file, as left by `aoc new`, doesn't count as cached.
+pub fn fetch_input(client: &Client, year: u16, day: u8, dir: &Path) -> Result<PathBuf> {
+    if !dir.is_dir() {
+        return Err(anyhow!(
+            "{} does not exist, create it with `aoc new {day}` first",
+            dir.display()
+        ));
+    }
+    let path = dir.join("input");
+    if fs::metadata(&path).is_ok_and(|meta| meta.len() > 0) {
+        return Err(anyhow!(
+            "{} is already downloaded, delete it to fetch it again",
+            path.display()
+        ));
+    }
+    let input = client.input(year, day)?;
+    fs::write(&path, input).with_context(|| format!("writing {}", path.display()))?;
+    Ok(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::mock::Server;
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("aoc-fetch-{name}-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn test_fetch() {
+        let server = Server::start(vec![(200, "1 2 3\n".to_string())]);
+        let client = Client::new(&server.config()).unwrap();
+        let dir = temp_dir("ok");
+        fs::write(dir.join("input"), "").unwrap();
+
+        let path = fetch_input(&client, 2023, 9, &dir).unwrap();
+        assert_eq!(fs::read_to_string(path).unwrap(), "1 2 3\n");
+
+        let request = server.request().to_ascii_lowercase();
+        assert!(request.starts_with("get /2023/day/9/input "));
+        assert!(request.contains("cookie: session=abc123"));
+        assert!(request.contains("user-agent: aoc-runner/"));
+        assert!(request.contains("(test@example.com)"));
+
+        // cached now, so this must not reach the server
+        assert!(fetch_input(&client, 2023, 9, &dir).is_err());
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_fetch_error_keeps_cache_empty() {
+        let server = Server::start(vec![(400, "Please log in".to_string())]);
+        let client = Client::new(&server.config()).unwrap();
+        let dir = temp_dir("err");
+
+        let err = fetch_input(&client, 2023, 9, &dir).unwrap_err();
+        assert!(err.to_string().contains("session token"), "{err}");
+        assert!(!dir.join("input").exists());
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,128 @@
+//! Where a day's input comes from: its input file, another file, stdin or one
+//! of its examples.
+
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::{fs, io};
+
+use anyhow::{Context, Result};
+use aoc_common::examples;
+use aoc_common::known::PartAnswers;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Source {
+    /// puzzle_NN/input
+    Default,
+    /// Given as `-`
+    Stdin,
+    File(PathBuf),
+    /// puzzle_NN/examples/NN.txt
+    Example(u32),
+}
+
+/// Input text and a name for it in error messages
+#[derive(Debug)]
+pub struct Input {
+    pub name: String,
+    pub text: String,
+    /// The answers recorded for an example
+    pub expected: Option<PartAnswers>,
+}
+
+impl Source {
+    /// From the `input_file` argument and `--example` option, which clap keeps
+    /// from both being given
+    pub fn new(input_file: Option<PathBuf>, example: Option<u32>) -> Self {
+        match (input_file, example) {
+            (_, Some(number)) => Source::Example(number),
+            (Some(path), None) if path.as_os_str() == "-" => Source::Stdin,
+            (Some(path), None) => Source::File(path),
+            (None, None) => Source::Default,
+        }
+    }
+
+    /// The file this reads for the day in `day_dir`, `None` for stdin
+    pub fn path(&self, day_dir: &Path) -> Option<PathBuf> {
+        match self {
+            Source::Default => Some(day_dir.join("input")),
+            Source::Stdin => None,
+            Source::File(path) => Some(path.clone()),
+            Source::Example(number) => Some(examples::input_path(day_dir, *number)),
+        }
+    }
+
+    pub fn read(&self, day_dir: &Path) -> Result<Input> {
+        match self.path(day_dir) {
+            Some(path) => {
+                let answers = examples::answers_path(&path);
+                let expected = match self {
+                    Source::Example(_) if answers.exists() => Some(
+                        examples::load_answers(&answers)
+                            .with_context(|| format!("could not read {}", answers.display()))?,
+                    ),
+                    _ => None,
+                };
+                Ok(Input {
+                    name: path.display().to_string(),
+                    text: read_input(&path)?,
+                    expected,
+                })
+            }
+            None => {
+                let mut text = String::new();
+                io::stdin()
+                    .read_to_string(&mut text)
+                    .context("could not read input from stdin")?;
+                Ok(Input {
+                    name: "stdin".to_string(),
+                    text,
+                    expected: None,
+                })
+            }
+        }
+    }
+}
+
+pub fn read_input(path: &Path) -> Result<String> {
+    fs::read_to_string(path).with_context(|| format!("could not read input {}", path.display()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::{Answer, Part};
+
+    #[test]
+    fn test_sources() {
+        let day_dir = Path::new("puzzle_09");
+        assert_eq!(Source::new(None, None), Source::Default);
+        assert_eq!(Source::new(Some("-".into()), None), Source::Stdin);
+        assert_eq!(Source::new(None, Some(2)), Source::Example(2));
+        assert_eq!(
+            Source::new(Some("in.txt".into()), None).path(day_dir),
+            Some(PathBuf::from("in.txt"))
+        );
+        assert_eq!(
+            Source::Default.path(day_dir),
+            Some(PathBuf::from("puzzle_09/input"))
+        );
+        assert_eq!(
+            Source::Example(2).path(day_dir),
+            Some(PathBuf::from("puzzle_09/examples/02.txt"))
+        );
+        assert_eq!(Source::Stdin.path(day_dir), None);
+    }
+
+    #[test]
+    fn test_read() {
+        let day_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("../puzzle_09");
+        let input = Source::Example(1).read(&day_dir).unwrap();
+        assert!(input.name.ends_with("examples/01.txt"));
+        assert!(input.text.starts_with("0 3 6 9 12 15"));
+        let expected = input.expected.unwrap();
+        assert_eq!(expected.get(Part::A), Some(&Answer::from(114i64)));
+
+        let err = Source::Example(99).read(&day_dir).unwrap_err();
+        assert!(err.to_string().contains("examples/99.txt"), "{err}");
+    }
+}
@@ -0,0 +1,483 @@
+use std::cell::RefCell;
+use std::io::Stdout;
+use std::path::PathBuf;
+use std::rc::Rc;
+use std::str::FromStr;
+use std::time;
+
+use anyhow::{anyhow, Result};
+use aoc_common::{gen, Part};
+use aoc_grid::frames;
+use clap::{Parser, Subcommand};
+
+mod animate;
+mod bench;
+mod client;
+mod days;
+mod examples;
+mod fetch;
+mod input;
+mod render;
+mod scaffold;
+mod submit;
+mod verify;
+
+use animate::Player;
+use days::Day;
+use input::{read_input, Input, Source};
+
+#[derive(Parser, Debug)]
+#[command()]
+struct Args {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Run the solvers for one day, or for every day
+    Run {
+        /// Day number, or "all"
+        day: DaySelector,
+        /// Only run one part
+        #[arg(long)]
+        part: Option<Part>,
+        /// Run on puzzle_NN/examples/NN.txt instead
+        #[arg(long, conflicts_with = "input_file")]
+        example: Option<u32>,
+        /// Draw the steps of solvers that emit frames as a terminal animation
+        #[arg(long)]
+        animate: bool,
+        /// Animation speed
+        #[arg(long, default_value_t = 20.0, requires = "animate")]
+        fps: f64,
+        /// Start the animation paused, stepping a frame at a time with Enter
+        #[arg(long, requires = "animate")]
+        paused: bool,
+        /// Save the last frame as a picture, .svg or .png
+        #[arg(long)]
+        render: Option<PathBuf>,
+        /// Save this frame instead of the last, counting from 1
+        #[arg(long, requires = "render")]
+        frame: Option<usize>,
+        /// Also show how each answer comes about, for days that can
+        #[arg(long)]
+        explain: bool,
+        /// Instead of solving, convert --value from this category to the --to one,
+        /// for days with categories
+        #[arg(long, requires_all = ["to", "value"])]
+        from: Option<String>,
+        /// Category to convert to
+        #[arg(long, requires = "from")]
+        to: Option<String>,
+        /// Value to convert
+        #[arg(long, requires = "from", allow_negative_numbers = true)]
+        value: Option<i64>,
+        /// Input file, or - for stdin; defaults to puzzle_NN/input
+        input_file: Option<PathBuf>,
+    },
+    /// Check every solver against the answers recorded in puzzle_NN/answers.toml
+    Verify {
+        /// Day number, or "all"
+        #[arg(default_value = "all")]
+        day: DaySelector,
+        /// Save answers that are not known yet to answers.toml
+        #[arg(long)]
+        record: bool,
+    },
+    /// Time the parse and solve phases of one day over repeated runs
+    Bench {
+        /// Day number
+        day: u8,
+        /// Only bench one part
+        #[arg(long)]
+        part: Option<Part>,
+        /// Untimed runs before measuring
+        #[arg(long, default_value_t = 3)]
+        warmup: usize,
+        /// Measured runs
+        #[arg(long, default_value_t = 20)]
+        runs: usize,
+        /// Write the results as JSON
+        #[arg(long)]
+        save: Option<PathBuf>,
+        /// Compare against JSON results saved by an earlier run
+        #[arg(long)]
+        baseline: Option<PathBuf>,
+        /// Median change, in percent, below which a difference is not reported
+        #[arg(long, default_value_t = 5.0)]
+        threshold: f64,
+        /// Bench on puzzle_NN/examples/NN.txt instead
+        #[arg(long, conflicts_with = "input_file")]
+        example: Option<u32>,
+        /// Input file, or - for stdin; defaults to puzzle_NN/input
+        input_file: Option<PathBuf>,
+    },
+    /// Print a random input for a day, to stress test or fuzz its solver
+    Gen {
+        /// Day number
+        day: u8,
+        /// The same seed gives the same input; a random one is picked and shown if not given
+        #[arg(long)]
+        seed: Option<u64>,
+        /// How big an input to make; what it counts depends on the day
+        #[arg(long, default_value_t = 10)]
+        size: usize,
+    },
+    /// Download a day's input into puzzle_NN/input
+    Fetch {
+        /// Puzzle year
+        year: u16,
+        /// Day number
+        day: u8,
+    },
+    /// Solve one part on puzzle_NN/input and post the answer
+    Submit {
+        /// Day number
+        day: u8,
+        /// Part to submit
+        part: Part,
+        /// Puzzle year, defaults to the configured year
+        #[arg(long)]
+        year: Option<u16>,
+    },
+    /// Save the examples and expected answers from a puzzle page into puzzle_NN/examples
+    Examples {
+        /// Day number
+        day: u8,
+        /// The puzzle description, saved from the browser
+        page: PathBuf,
+    },
+    /// Create puzzle_NN from template/ and register it with the workspace and runner
+    New {
+        /// Day number
+        day: u8,
+    },
+}
+
+#[derive(Debug, Clone, Copy)]
+enum DaySelector {
+    All,
+    One(u8),
+}
+
+impl FromStr for DaySelector {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.eq_ignore_ascii_case("all") {
+            return Ok(DaySelector::All);
+        }
+        s.parse::<u8>()
+            .map(DaySelector::One)
+            .map_err(|_| format!("expected a day number or \"all\", got \"{s}\""))
+    }
+}
+
+fn main() -> Result<()> {
+    let cli = Args::parse();
+    match cli.command {
+        Command::Run {
+            day,
+            part,
+            example,
+            animate,
+            fps,
+            paused,
+            render,
+            frame,
+            explain,
+            from,
+            to,
+            value,
+            input_file,
+        } => {
+            let source = Source::new(input_file, example);
+            match day {
+                DaySelector::One(number) => {
+                    let day =
+                        days::find(number).ok_or_else(|| anyhow!("day {number} is not solved"))?;
+                    if let (Some(from), Some(to), Some(value)) = (from, to, value) {
+                        let input = source.read(&day.dir())?;
+                        let found = day.convert(&input.text, &input.name, (&from, &to), value)?;
+                        let found = found.iter().map(|v| v.to_string()).collect::<Vec<_>>();
+                        match found.is_empty() {
+                            true => println!("no {to} converts to {from} {value}"),
+                            false => println!("{from} {value} is {to} {}", found.join(", ")),
+                        }
+                        return Ok(());
+                    }
+                    if explain && day.explain.is_none() {
+                        return Err(anyhow!("day {number} has nothing to explain"));
+                    }
+                    let input = source.read(&day.dir())?;
+                    let visuals = Visuals {
+                        player: animate
+                            .then(|| Rc::new(RefCell::new(Player::terminal(fps, paused)))),
+                        render: render.map(|path| (path, frame)),
+                    };
+                    run_day(day, &input, part, &visuals, explain)
+                }
+                DaySelector::All if animate || render.is_some() || explain || from.is_some() => {
+                    Err(anyhow!(
+                        "--animate, --render, --explain and --from need a single day"
+                    ))
+                }
+                DaySelector::All => run_all(&source, part),
+            }
+        }
+        Command::Verify { day, record } => {
+            let mut summary = verify::Summary::default();
+            match day {
+                DaySelector::One(number) => {
+                    let day =
+                        days::find(number).ok_or_else(|| anyhow!("day {number} is not solved"))?;
+                    verify::verify_day(day, record, &mut summary)?;
+                }
+                DaySelector::All => {
+                    for day in days::DAYS {
+                        verify::verify_day(day, record, &mut summary)?;
+                    }
+                }
+            }
+            summary.check()
+        }
+        Command::Bench {
+            day,
+            part,
+            warmup,
+            runs,
+            save,
+            baseline,
+            threshold,
+            example,
+            input_file,
+        } => {
+            if runs == 0 {
+                return Err(anyhow!("--runs must be at least 1"));
+            }
+            let day = days::find(day).ok_or_else(|| anyhow!("day {day} is not solved"))?;
+            let input = Source::new(input_file, example).read(&day.dir())?;
+            let baseline = baseline.as_deref().map(bench::load).transpose()?;
+            let config = bench::BenchConfig { warmup, runs };
+
+            let mut reports = Vec::new();
+            for part in parts(part) {
+                let report = (day.bench)(day.number, &input.text, part, &config)
+                    .map_err(|err| days::parse_failure(&input.name, &input.text, &err))?;
+                let base = baseline
+                    .iter()
+                    .flatten()
+                    .find(|base| base.day == report.day && base.part == report.part);
+                report.print(base, threshold);
+                reports.push(report);
+            }
+            if let Some(path) = save {
+                bench::save(&path, &reports)?;
+                println!("Saved results to {}", path.display());
+            }
+            Ok(())
+        }
+        Command::Gen { day, seed, size } => {
+            let day = days::find(day).ok_or_else(|| anyhow!("day {day} is not solved"))?;
+            let seed = seed.unwrap_or_else(|| {
+                let seed = time::SystemTime::now()
+                    .duration_since(time::UNIX_EPOCH)
+                    .map_or(0, |since| since.as_nanos() as u64);
+                eprintln!("seed {seed}");
+                seed
+            });
+            print!("{}", (day.generate)(&mut gen::seeded(seed), size));
+            Ok(())
+        }
+        Command::Fetch { year, day } => {
+            let root = days::workspace_root();
+            let client = client::Client::new(&client::Config::load(&root)?)?;
+            let dir = root.join(format!("puzzle_{day:02}"));
+            let path = fetch::fetch_input(&client, year, day, &dir)?;
+            println!("Saved input to {}", path.display());
+            Ok(())
+        }
+        Command::Submit { day, part, year } => {
+            let root = days::workspace_root();
+            let config = client::Config::load(&root)?;
+            let client = client::Client::new(&config)?;
+            let day = days::find(day).ok_or_else(|| anyhow!("day {day} is not solved"))?;
+            let path = day.dir().join("input");
+            let input = read_input(&path)?;
+            let answer = day.run(&input, &path.display().to_string(), part)?;
+            println!("Submitting {answer} for day {:02} part {part}", day.number);
+            let year = year.unwrap_or_else(|| config.year());
+            let outcome = submit::submit(&client, year, day.number, &day.dir(), part, &answer)?;
+            println!("{outcome}");
+            Ok(())
+        }
+        Command::Examples { day, page } => {
+            let dir = days::workspace_root().join(format!("puzzle_{day:02}"));
+            if !dir.is_dir() {
+                return Err(anyhow!("{} does not exist", dir.display()));
+            }
+            for path in examples::extract_to_day(&dir, &page)? {
+                println!("Saved {}", path.display());
+            }
+            Ok(())
+        }
+        Command::New { day } => {
+            let dir = scaffold::new_day(&days::workspace_root(), day)?;
+            println!("Created {}", dir.display());
+            println!(
+                "Paste the puzzle input into input, and the example and its answers into examples/"
+            );
+            Ok(())
+        }
+    }
+}
+
+/// What to do with the frames a solver emits
+#[derive(Default)]
+struct Visuals {
+    player: Option<Rc<RefCell<Player<Stdout>>>>,
+    /// Where to save a picture, and which frame to save if not the last
+    render: Option<(PathBuf, Option<usize>)>,
+}
+
+fn run_day(
+    day: &Day,
+    input: &Input,
+    part: Option<Part>,
+    visuals: &Visuals,
+    explain: bool,
+) -> Result<()> {
+    println!("Day {:02}", day.number);
+
+    // an example usually only comes with answers for one part, and may not suit the other
+    let parts = match (&input.expected, part) {
+        (Some(expected), None) => parts(None)
+            .into_iter()
+            .filter(|&part| expected.get(part).is_some())
+            .collect(),
+        _ => parts(part),
+    };
+    let multiple = parts.len() > 1;
+    // the animation draws over anything printed before it ends
+    let mut results = Vec::new();
+    for part in parts {
+        let t0 = time::Instant::now();
+        let (result, picture) = run_part(day, input, part, visuals);
+        let result = result?;
+        let dur = time::Instant::now() - t0;
+        let line = match input
+            .expected
+            .as_ref()
+            .and_then(|expected| expected.get(part))
+        {
+            Some(expected) => format!("Result {part}: {result} in {dur:?} (expected {expected})"),
+            None => format!("Result {part}: {result} in {:?}", dur),
+        };
+        match visuals.player {
+            Some(_) => results.push(line),
+            None => println!("{line}"),
+        }
+        if explain {
+            let explanation = day.explain(&input.text, &input.name, part)?;
+            match visuals.player {
+                Some(_) => results.push(explanation.trim_end().to_string()),
+                None => print!("{explanation}"),
+            }
+        }
+        if let Some((path, frame)) = &visuals.render {
+            let path = match multiple {
+                true => render::part_path(path, part),
+                false => path.clone(),
+            };
+            match picture {
+                Some(picture) => {
+                    render::save(&picture, &path)?;
+                    results.push(format!("Saved {}", path.display()));
+                }
+                None => {
+                    return Err(match frame {
+                        Some(n) => anyhow!("part {part} emitted fewer than {n} frames"),
+                        None => anyhow!("day {} part {part} emits no frames", day.number),
+                    })
+                }
+            }
+        }
+    }
+    for line in results {
+        println!("{line}");
+    }
+    Ok(())
+}
+
+/// Solve one part, showing its frames and keeping the one to render
+fn run_part(
+    day: &Day,
+    input: &Input,
+    part: Part,
+    visuals: &Visuals,
+) -> (Result<aoc_common::Answer>, Option<frames::Frame>) {
+    if visuals.player.is_none() && visuals.render.is_none() {
+        return (day.run(&input.text, &input.name, part), None);
+    }
+    let player = visuals.player.clone();
+    let wanted = visuals.render.as_ref().map(|(_, frame)| *frame);
+    let kept = Rc::new(RefCell::new(None));
+    let keep = kept.clone();
+    let mut count = 0;
+    let result = frames::with_sink(
+        move |frame| {
+            count += 1;
+            if let Some(player) = &player {
+                player.borrow_mut().show(&frame);
+            }
+            match wanted {
+                Some(Some(n)) if n != count => (),
+                Some(_) => *keep.borrow_mut() = Some(frame),
+                None => (),
+            }
+        },
+        || day.run(&input.text, &input.name, part),
+    );
+    if let Some(player) = &visuals.player {
+        player.borrow_mut().finish();
+    }
+    let picture = kept.borrow_mut().take();
+    (result, picture)
+}
+
+/// Every day's own input, or every day's example, skipping days that don't have one
+fn run_all(source: &Source, part: Option<Part>) -> Result<()> {
+    if matches!(source, Source::Stdin | Source::File(_)) {
+        return Err(anyhow!("an input file can only be given for a single day"));
+    }
+    for day in days::DAYS {
+        let path = source
+            .path(&day.dir())
+            .expect("every day has its own input");
+        if !path.exists() {
+            println!(
+                "Day {:02}: no input at {}, skipping",
+                day.number,
+                path.display()
+            );
+            continue;
+        }
+        run_day(
+            day,
+            &source.read(&day.dir())?,
+            part,
+            &Visuals::default(),
+            false,
+        )?;
+    }
+    Ok(())
+}
+
+/// The requested part, or both
+fn parts(part: Option<Part>) -> Vec<Part> {
+    match part {
+        Some(part) => vec![part],
+        None => vec![Part::A, Part::B],
+    }
+}
@@ -0,0 +1,268 @@
+//! `aoc run --render`: save a frame a solver emitted as an SVG or PNG picture.
+
+use std::fmt::Write as _;
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::{Path, PathBuf};
+
+use anyhow::{anyhow, Context, Result};
+use aoc_common::Part;
+use aoc_grid::frames::{Frame, Line};
+use aoc_grid::Coord;
+
+/// Pixels along the side of a cell
+const CELL: usize = 12;
+/// Share of a highlighted or marked cell's color taken from the overlay
+const TINT: f64 = 0.6;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rgb(pub u8, pub u8, pub u8);
+
+impl Rgb {
+    fn blend(self, over: Rgb, amount: f64) -> Rgb {
+        let mix = |a: u8, b: u8| (a as f64 * (1.0 - amount) + b as f64 * amount).round() as u8;
+        Rgb(
+            mix(self.0, over.0),
+            mix(self.1, over.1),
+            mix(self.2, over.2),
+        )
+    }
+
+    fn hex(self) -> String {
+        format!("#{:02x}{:02x}{:02x}", self.0, self.1, self.2)
+    }
+}
+
+const HIGHLIGHT: Rgb = Rgb(250, 200, 40);
+const MARK: Rgb = Rgb(60, 180, 170);
+const LINE: Rgb = Rgb(220, 40, 40);
+const TEXT: Rgb = Rgb(30, 30, 30);
+
+/// The fill for each kind of cell
+pub fn palette(cell: char) -> Rgb {
+    match cell {
+        '.' => Rgb(245, 243, 235),
+        '#' => Rgb(70, 70, 80),
+        'O' => Rgb(225, 130, 40),
+        '|' | '-' | 'L' | 'J' | '7' | 'F' => Rgb(150, 175, 220),
+        'S' => Rgb(210, 70, 70),
+        c if c.is_ascii_digit() => Rgb(120, 170, 110),
+        _ => Rgb(170, 140, 200),
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Format {
+    Svg,
+    Png,
+}
+
+impl Format {
+    fn of(path: &Path) -> Result<Self> {
+        match path.extension().and_then(|ext| ext.to_str()) {
+            Some(ext) if ext.eq_ignore_ascii_case("svg") => Ok(Format::Svg),
+            Some(ext) if ext.eq_ignore_ascii_case("png") => Ok(Format::Png),
+            _ => Err(anyhow!(
+                "can only render to .svg or .png, not {}",
+                path.display()
+            )),
+        }
+    }
+}
+
+/// out.svg -> out_a.svg, for saving a picture of each part
+pub fn part_path(path: &Path, part: Part) -> PathBuf {
+    let stem = path.file_stem().unwrap_or_default().to_string_lossy();
+    let part = part.to_string().to_lowercase();
+    let name = match path.extension() {
+        Some(ext) => format!("{stem}_{part}.{}", ext.to_string_lossy()),
+        None => format!("{stem}_{part}"),
+    };
+    path.with_file_name(name)
+}
+
+/// Save `frame` to `path`, as SVG or PNG going by its extension
+pub fn save(frame: &Frame, path: &Path) -> Result<()> {
+    let format = Format::of(path)?;
+    let file =
+        File::create(path).with_context(|| format!("could not create {}", path.display()))?;
+    let mut out = BufWriter::new(file);
+    match format {
+        Format::Svg => out.write_all(svg(frame).as_bytes())?,
+        Format::Png => png(frame, &mut out)?,
+    }
+    out.flush()
+        .with_context(|| format!("could not write {}", path.display()))
+}
+
+/// Each cell's color, with the highlights and marks tinted over it
+fn fills(frame: &Frame) -> aoc_grid::Grid<Rgb> {
+    let mut fills = frame.cells.map(|&cell| palette(cell));
+    for (coords, tint) in [(&frame.marks, MARK), (&frame.highlights, HIGHLIGHT)] {
+        for &coord in coords {
+            if let Some(fill) = fills.get_mut(coord) {
+                *fill = fill.blend(tint, TINT);
+            }
+        }
+    }
+    fills
+}
+
+/// Pixel end points of a line along the grid
+fn line_ends(frame: &Frame, line: Line) -> ((usize, usize), (usize, usize)) {
+    let (width, height) = (frame.cells.width() * CELL, frame.cells.height() * CELL);
+    match line {
+        Line::Row(row) => ((0, row * CELL), (width, row * CELL)),
+        Line::Column(col) => ((col * CELL, 0), (col * CELL, height)),
+    }
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+pub fn svg(frame: &Frame) -> String {
+    let (width, height) = (frame.cells.width() * CELL, frame.cells.height() * CELL);
+    let mut out = String::new();
+    let _ = writeln!(
+        out,
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="{width}" height="{height}" viewBox="0 0 {width} {height}" font-family="monospace" font-size="{}" text-anchor="middle" dominant-baseline="central">"#,
+        CELL * 3 / 4
+    );
+    if !frame.caption.is_empty() {
+        let _ = writeln!(out, "<title>{}</title>", escape(&frame.caption));
+    }
+    let fills = fills(frame);
+    for (Coord { row, col }, fill) in fills.iter() {
+        let (x, y) = (col * CELL, row * CELL);
+        let _ = writeln!(
+            out,
+            r#"<rect x="{x}" y="{y}" width="{CELL}" height="{CELL}" fill="{}"/>"#,
+            fill.hex()
+        );
+        let cell = frame.cells[Coord::new(row, col)];
+        if cell != '.' {
+            let text = match fill.0 as u32 + fill.1 as u32 + fill.2 as u32 > 3 * 128 {
+                true => TEXT,
+                false => palette('.'),
+            };
+            let _ = writeln!(
+                out,
+                r#"<text x="{}" y="{}" fill="{}">{}</text>"#,
+                x + CELL / 2,
+                y + CELL / 2,
+                text.hex(),
+                escape(&cell.to_string())
+            );
+        }
+    }
+    for &line in &frame.lines {
+        let ((x1, y1), (x2, y2)) = line_ends(frame, line);
+        let _ = writeln!(
+            out,
+            r#"<line x1="{x1}" y1="{y1}" x2="{x2}" y2="{y2}" stroke="{}" stroke-width="2"/>"#,
+            LINE.hex()
+        );
+    }
+    out.push_str("</svg>\n");
+    out
+}
+
+pub fn png(frame: &Frame, out: impl Write) -> Result<()> {
+    let (width, height) = (frame.cells.width() * CELL, frame.cells.height() * CELL);
+    let mut pixels = vec![0u8; width * height * 3];
+    let mut paint = |x: usize, y: usize, Rgb(r, g, b): Rgb| {
+        if x < width && y < height {
+            let i = (y * width + x) * 3;
+            pixels[i..i + 3].copy_from_slice(&[r, g, b]);
+        }
+    };
+    for (Coord { row, col }, &fill) in fills(frame).iter() {
+        for y in row * CELL..(row + 1) * CELL {
+            for x in col * CELL..(col + 1) * CELL {
+                paint(x, y, fill);
+            }
+        }
+    }
+    for &line in &frame.lines {
+        // two pixels wide, straddling the boundary
+        let ((x1, y1), (x2, y2)) = line_ends(frame, line);
+        for y in y1.saturating_sub(1)..=y2 {
+            for x in x1.saturating_sub(1)..=x2 {
+                paint(x, y, LINE);
+            }
+        }
+    }
+
+    let mut encoder = png::Encoder::new(out, width as u32, height as u32);
+    encoder.set_color(png::ColorType::Rgb);
+    encoder.set_depth(png::BitDepth::Eight);
+    let mut writer = encoder.write_header()?;
+    writer.write_image_data(&pixels)?;
+    writer.finish()?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_grid::Grid;
+
+    use super::*;
+
+    fn frame() -> Frame {
+        let grid = Grid::parse("#.\nO.\n", Some, "anything").unwrap();
+        Frame::new(&grid)
+            .highlight([Coord::new(1, 0)])
+            .mark([Coord::new(1, 1)])
+            .line(Line::Row(1))
+            .caption("a < b")
+    }
+
+    #[test]
+    fn test_svg() {
+        let svg = svg(&frame());
+        assert!(svg.starts_with("<svg "));
+        assert!(svg.contains(r#"width="24" height="24""#));
+        assert!(svg.contains("<title>a &lt; b</title>"));
+        assert_eq!(svg.matches("<rect ").count(), 4);
+        // '.' cells get no text
+        assert_eq!(svg.matches("<text ").count(), 2);
+        let highlighted = palette('O').blend(HIGHLIGHT, TINT).hex();
+        assert!(svg.contains(&format!(
+            r#"<rect x="0" y="12" width="12" height="12" fill="{highlighted}"/>"#
+        )));
+        assert!(svg.contains(r#"<line x1="0" y1="12" x2="24" y2="12""#));
+    }
+
+    #[test]
+    fn test_png() {
+        let mut encoded = Vec::new();
+        png(&frame(), &mut encoded).unwrap();
+        let mut reader = png::Decoder::new(encoded.as_slice()).read_info().unwrap();
+        let mut pixels = vec![0; reader.output_buffer_size()];
+        let info = reader.next_frame(&mut pixels).unwrap();
+        assert_eq!((info.width, info.height), (24, 24));
+        let at = |x: usize, y: usize| {
+            let i = (y * 24 + x) * 3;
+            Rgb(pixels[i], pixels[i + 1], pixels[i + 2])
+        };
+        assert_eq!(at(0, 0), palette('#'));
+        assert_eq!(at(18, 0), palette('.'));
+        assert_eq!(at(18, 18), palette('.').blend(MARK, TINT));
+        assert_eq!(at(5, 11), LINE);
+        assert_eq!(at(5, 12), LINE);
+        assert_eq!(at(5, 13), palette('O').blend(HIGHLIGHT, TINT));
+    }
+
+    #[test]
+    fn test_paths() {
+        assert_eq!(
+            part_path(Path::new("out/day14.svg"), Part::B),
+            PathBuf::from("out/day14_b.svg")
+        );
+        assert!(Format::of(Path::new("x.gif")).is_err());
+        assert_eq!(Format::of(Path::new("x.PNG")).unwrap(), Format::Png);
+    }
+}
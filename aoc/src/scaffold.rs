@@ -0,0 +1,258 @@
+//! `aoc new`: create a puzzle_NN crate from template/ and hook it up to the
+//! workspace and the runner.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::{anyhow, Context, Result};
+
+/// Placeholder for the crate name in the files under template/
+const NAME_PLACEHOLDER: &str = "puzzle_NN";
+
+pub fn new_day(root: &Path, number: u8) -> Result<PathBuf> {
+    let name = format!("puzzle_{number:02}");
+    let dest = root.join(&name);
+    if dest.exists() {
+        return Err(anyhow!("{} already exists", dest.display()));
+    }
+
+    // work out every edit before touching the tree, so a manifest we can't
+    // update doesn't leave a half-made day behind
+    let edits = [
+        edit(&root.join("Cargo.toml"), |manifest| {
+            add_workspace_member(manifest, &name)
+        })?,
+        edit(&root.join("aoc").join("Cargo.toml"), |manifest| {
+            add_runner_dependency(manifest, &name)
+        })?,
+        edit(&root.join("aoc").join("src").join("days.rs"), |days| {
+            register_day(days, number, &name)
+        })?,
+    ];
+
+    // build the crate beside its final place and only rename it in once it's whole
+    let staging = root.join(format!(".{name}.new"));
+    if staging.exists() {
+        fs::remove_dir_all(&staging)?;
+    }
+    let staged = copy_template(&root.join("template"), &staging, &name)
+        .and_then(|()| Ok(fs::write(staging.join("input"), "")?))
+        .and_then(|()| Ok(fs::rename(&staging, &dest)?));
+    if let Err(err) = staged {
+        let _ = fs::remove_dir_all(&staging);
+        return Err(err);
+    }
+
+    for (path, contents) in edits {
+        fs::write(&path, contents).with_context(|| format!("writing {}", path.display()))?;
+    }
+    Ok(dest)
+}
+
+fn copy_template(src: &Path, dest: &Path, name: &str) -> Result<()> {
+    fs::create_dir_all(dest)?;
+    for entry in fs::read_dir(src).with_context(|| format!("reading {}", src.display()))? {
+        let entry = entry?;
+        let path = entry.path();
+        if entry.file_name() == "target" {
+            continue;
+        }
+        let target = dest.join(entry.file_name());
+        if entry.file_type()?.is_dir() {
+            copy_template(&path, &target, name)?;
+        } else {
+            let contents = fs::read_to_string(&path)?;
+            fs::write(&target, contents.replace(NAME_PLACEHOLDER, name))?;
+        }
+    }
+    Ok(())
+}
+
+/// Read `path` and apply `edit` to it, returning the path and the new contents
+/// to write once everything else has succeeded.
+fn edit(path: &Path, edit: impl FnOnce(&str) -> Result<String>) -> Result<(PathBuf, String)> {
+    let contents =
+        fs::read_to_string(path).with_context(|| format!("reading {}", path.display()))?;
+    let updated = edit(&contents).with_context(|| format!("updating {}", path.display()))?;
+    Ok((path.to_path_buf(), updated))
+}
+
+/// Insert `line` among the lines between `start` and the next `end`, keeping
+/// the lines for which `sort_key` returns Some in order.
+fn insert_sorted<K: Ord>(
+    text: &str,
+    start: &str,
+    end: &str,
+    line: String,
+    sort_key: impl Fn(&str) -> Option<K>,
+) -> Result<String> {
+    let mut lines = text.lines().map(str::to_string).collect::<Vec<_>>();
+    let start_idx = lines
+        .iter()
+        .position(|l| l.trim() == start)
+        .ok_or_else(|| anyhow!("no \"{start}\" line"))?;
+    let end_idx = start_idx
+        + lines[start_idx..]
+            .iter()
+            .position(|l| l.trim() == end)
+            .ok_or_else(|| anyhow!("no \"{end}\" after \"{start}\""))?;
+
+    let new_key = sort_key(&line).expect("the inserted line has a sort key");
+    let mut insert_at = end_idx;
+    for (idx, existing) in lines.iter().enumerate().take(end_idx).skip(start_idx + 1) {
+        match sort_key(existing) {
+            Some(key) if key == new_key => return Err(anyhow!("already contains {}", line.trim())),
+            Some(key) if key > new_key => {
+                insert_at = idx;
+                break;
+            }
+            _ => (),
+        }
+    }
+    lines.insert(insert_at, line);
+    Ok(lines.join("\n") + "\n")
+}
+
+fn add_workspace_member(manifest: &str, name: &str) -> Result<String> {
+    insert_sorted(
+        manifest,
+        "members = [",
+        "]",
+        format!("    \"{name}\","),
+        |line| {
+            let member = line.trim().trim_end_matches(',').trim_matches('"');
+            member.starts_with("puzzle_").then(|| member.to_string())
+        },
+    )
+}
+
+fn add_runner_dependency(manifest: &str, name: &str) -> Result<String> {
+    let line = format!("{name} = {{ path = \"../{name}\" }}");
+    let mut lines = manifest.lines().collect::<Vec<_>>();
+    if lines.iter().any(|l| l.starts_with(&format!("{name} "))) {
+        return Err(anyhow!("already depends on {name}"));
+    }
+    let start = lines
+        .iter()
+        .position(|l| l.trim() == "[dependencies]")
+        .ok_or_else(|| anyhow!("no [dependencies] table"))?;
+
+    let mut insert_at = start + 1;
+    for (idx, existing) in lines.iter().enumerate().skip(start + 1) {
+        if existing.starts_with('[') {
+            break;
+        }
+        if existing.starts_with("puzzle_") && *existing > line.as_str() {
+            insert_at = idx;
+            break;
+        }
+        if !existing.trim().is_empty() {
+            insert_at = idx + 1;
+        }
+    }
+    lines.insert(insert_at, &line);
+    Ok(lines.join("\n") + "\n")
+}
+
+fn register_day(days_rs: &str, number: u8, name: &str) -> Result<String> {
+    insert_sorted(
+        days_rs,
+        "pub static DAYS: &[Day] = &[",
+        "];",
+        format!("    day!({number}, {name}),"),
+        |line| {
+            line.trim()
+                .strip_prefix("day!(")?
+                .split(',')
+                .next()?
+                .trim()
+                .parse::<u8>()
+                .ok()
+        },
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_add_workspace_member() {
+        let manifest =
+            "[workspace]\nmembers = [\n    \"aoc\",\n    \"puzzle_02\",\n    \"puzzle_14\",\n]\n";
+        let updated = add_workspace_member(manifest, "puzzle_12").unwrap();
+        assert_eq!(
+            updated,
+            "[workspace]\nmembers = [\n    \"aoc\",\n    \"puzzle_02\",\n    \"puzzle_12\",\n    \"puzzle_14\",\n]\n"
+        );
+        let updated = add_workspace_member(manifest, "puzzle_15").unwrap();
+        assert!(updated.ends_with("    \"puzzle_14\",\n    \"puzzle_15\",\n]\n"));
+        assert!(add_workspace_member(manifest, "puzzle_02").is_err());
+    }
+
+    #[test]
+    fn test_add_runner_dependency() {
+        let manifest = "[package]\nname = \"aoc\"\n\n[dependencies]\nclap.workspace = true\npuzzle_02 = { path = \"../puzzle_02\" }\npuzzle_14 = { path = \"../puzzle_14\" }\n";
+        let updated = add_runner_dependency(manifest, "puzzle_12").unwrap();
+        assert_eq!(
+            updated,
+            "[package]\nname = \"aoc\"\n\n[dependencies]\nclap.workspace = true\npuzzle_02 = { path = \"../puzzle_02\" }\npuzzle_12 = { path = \"../puzzle_12\" }\npuzzle_14 = { path = \"../puzzle_14\" }\n"
+        );
+        let updated = add_runner_dependency(manifest, "puzzle_15").unwrap();
+        assert!(updated.ends_with("puzzle_15 = { path = \"../puzzle_15\" }\n"));
+        assert!(add_runner_dependency(manifest, "puzzle_14").is_err());
+    }
+
+    #[test]
+    fn test_new_day_leaves_nothing_on_error() {
+        let root = std::env::temp_dir().join(format!("aoc-scaffold-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(root.join("template").join("src")).unwrap();
+        fs::create_dir_all(root.join("aoc").join("src")).unwrap();
+        fs::write(
+            root.join("template").join("Cargo.toml"),
+            "name = \"puzzle_NN\"\n",
+        )
+        .unwrap();
+        fs::write(root.join("template").join("src").join("lib.rs"), "").unwrap();
+        let workspace = "[workspace]\nmembers = [\n    \"aoc\",\n]\n";
+        fs::write(root.join("Cargo.toml"), workspace).unwrap();
+        fs::write(root.join("aoc").join("Cargo.toml"), "[dependencies]\n").unwrap();
+        // days.rs has no DAYS table to register the day in
+        fs::write(root.join("aoc").join("src").join("days.rs"), "").unwrap();
+
+        assert!(new_day(&root, 3).is_err());
+        assert!(!root.join("puzzle_03").exists());
+        assert!(!root.join(".puzzle_03.new").exists());
+        assert_eq!(
+            fs::read_to_string(root.join("Cargo.toml")).unwrap(),
+            workspace
+        );
+
+        fs::write(
+            root.join("aoc").join("src").join("days.rs"),
+            "pub static DAYS: &[Day] = &[\n];\n",
+        )
+        .unwrap();
+        let dest = new_day(&root, 3).unwrap();
+        assert_eq!(
+            fs::read_to_string(dest.join("Cargo.toml")).unwrap(),
+            "name = \"puzzle_03\"\n"
+        );
+        assert!(dest.join("input").exists());
+        assert!(!root.join(".puzzle_03.new").exists());
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn test_register_day() {
+        let days =
+            "pub static DAYS: &[Day] = &[\n    day!(2, puzzle_02),\n    day!(14, puzzle_14),\n];\n";
+        let updated = register_day(days, 12, "puzzle_12").unwrap();
+        assert_eq!(
+            updated,
+            "pub static DAYS: &[Day] = &[\n    day!(2, puzzle_02),\n    day!(12, puzzle_12),\n    day!(14, puzzle_14),\n];\n"
+        );
+        assert!(register_day(days, 2, "puzzle_02").is_err());
+    }
+}
@@ -0,0 +1,426 @@
+//! `aoc submit`: post an answer and keep track of what the site said about it.
+//!
+//! Every judged answer is recorded in puzzle_NN/guesses.toml together with any
+//! cooldown the site imposed, so a known-wrong answer, an answer outside the
+//! too high/too low bounds, or a submission during the cooldown never gets sent.
+
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use anyhow::{anyhow, Context, Result};
+use aoc_common::{Answer, KnownAnswers, Part};
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
+use crate::client::Client;
+
+pub const GUESSES_FILE: &str = "guesses.toml";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// Wrong, without a hint in which direction
+    Wrong,
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "correct"),
+            Verdict::TooHigh => write!(f, "too high"),
+            Verdict::TooLow => write!(f, "too low"),
+            Verdict::Wrong => write!(f, "wrong"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Outcome {
+    Judged(Verdict),
+    /// Submitted too soon after a wrong answer, the answer was not checked
+    Wait(Duration),
+    /// The part was already solved, the answer was not checked
+    AlreadySolved,
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Outcome::Judged(verdict) => write!(f, "{verdict}"),
+            Outcome::Wait(remaining) => {
+                write!(f, "too soon, wait {}", format_duration(*remaining))
+            }
+            Outcome::AlreadySolved => write!(f, "already solved"),
+        }
+    }
+}
+
+/// What the site said to a submission
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Response {
+    pub outcome: Outcome,
+    /// How long before the next answer will be accepted
+    pub cooldown: Option<Duration>,
+}
+
+/// Interpret the HTML page returned for a submission
+pub fn parse_response(html: &str) -> Result<Response> {
+    let text = article_text(html);
+    let outcome = if text.contains("That's the right answer") {
+        Outcome::Judged(Verdict::Correct)
+    } else if text.contains("That's not the right answer") {
+        Outcome::Judged(if text.contains("your answer is too high") {
+            Verdict::TooHigh
+        } else if text.contains("your answer is too low") {
+            Verdict::TooLow
+        } else {
+            Verdict::Wrong
+        })
+    } else if text.contains("You gave an answer too recently") {
+        let remaining = Regex::new(r"You have ((?:\d+[hms] ?)+) left to wait")
+            .unwrap()
+            .captures(&text)
+            .and_then(|caps| parse_hms(&caps[1]))
+            .ok_or_else(|| anyhow!("could not find the remaining cooldown in \"{text}\""))?;
+        Outcome::Wait(remaining)
+    } else if text.contains("Did you already complete it?") {
+        Outcome::AlreadySolved
+    } else {
+        return Err(anyhow!("unrecognized response: \"{text}\""));
+    };
+
+    let cooldown = match outcome {
+        Outcome::Wait(remaining) => Some(remaining),
+        Outcome::Judged(_) => Regex::new(r"(?i)please wait (\w+) minutes? before trying again")
+            .unwrap()
+            .captures(&text)
+            .and_then(|caps| parse_count(&caps[1]))
+            .map(|minutes| Duration::from_secs(60 * minutes)),
+        Outcome::AlreadySolved => None,
+    };
+    Ok(Response { outcome, cooldown })
+}
+
+/// The text of the page's `<article>`, with tags removed and whitespace collapsed
+fn article_text(html: &str) -> String {
+    let article = match (html.find("<article"), html.find("</article>")) {
+        (Some(start), Some(end)) if start < end => &html[start..end],
+        _ => html,
+    };
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in article.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// "1h 2m 3s", "5m 3s", "43s"
+fn parse_hms(s: &str) -> Option<Duration> {
+    let mut secs = 0;
+    for token in s.split_whitespace() {
+        let (n, unit) = token.split_at(token.len() - 1);
+        let n: u64 = n.parse().ok()?;
+        secs += match unit {
+            "h" => n * 3600,
+            "m" => n * 60,
+            "s" => n,
+            _ => return None,
+        };
+    }
+    Some(Duration::from_secs(secs))
+}
+
+/// "5" or "five"
+fn parse_count(s: &str) -> Option<u64> {
+    const WORDS: [&str; 10] = [
+        "one", "two", "three", "four", "five", "six", "seven", "eight", "nine", "ten",
+    ];
+    s.parse().ok().or_else(|| {
+        WORDS
+            .iter()
+            .position(|word| word.eq_ignore_ascii_case(s))
+            .map(|i| i as u64 + 1)
+    })
+}
+
+fn format_duration(duration: Duration) -> String {
+    let secs = duration.as_secs();
+    match (secs / 60, secs % 60) {
+        (0, s) => format!("{s}s"),
+        (m, s) => format!("{m}m {s}s"),
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Guess {
+    pub answer: String,
+    pub verdict: Verdict,
+    /// Unix timestamp of the submission
+    pub at: u64,
+}
+
+/// The guesses.toml record of submissions for one day
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Guesses {
+    /// Unix timestamp before which nothing may be submitted
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub wait_until: Option<u64>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub a: Vec<Guess>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub b: Vec<Guess>,
+}
+
+impl Guesses {
+    pub fn load(dir: &Path) -> Result<Self> {
+        let path = dir.join(GUESSES_FILE);
+        match fs::read_to_string(&path) {
+            Ok(text) => {
+                toml::from_str(&text).with_context(|| format!("parsing {}", path.display()))
+            }
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(err) => Err(err).with_context(|| format!("reading {}", path.display())),
+        }
+    }
+
+    pub fn save(&self, dir: &Path) -> Result<()> {
+        let path = dir.join(GUESSES_FILE);
+        fs::write(&path, toml::to_string(self)?)
+            .with_context(|| format!("writing {}", path.display()))
+    }
+
+    fn part(&self, part: Part) -> &Vec<Guess> {
+        match part {
+            Part::A => &self.a,
+            Part::B => &self.b,
+        }
+    }
+
+    fn part_mut(&mut self, part: Part) -> &mut Vec<Guess> {
+        match part {
+            Part::A => &mut self.a,
+            Part::B => &mut self.b,
+        }
+    }
+
+    /// Refuse a submission the site would reject, or that it has already judged
+    pub fn check(&self, part: Part, answer: &str, now: u64) -> Result<()> {
+        if let Some(wait_until) = self.wait_until.filter(|&t| t > now) {
+            return Err(anyhow!(
+                "still cooling down, wait {} before submitting",
+                format_duration(Duration::from_secs(wait_until - now))
+            ));
+        }
+        let value = answer.parse::<i128>().ok();
+        for guess in self.part(part) {
+            if guess.verdict == Verdict::Correct {
+                return Err(anyhow!(
+                    "part {part} is already solved with {}",
+                    guess.answer
+                ));
+            }
+            if guess.answer == answer {
+                return Err(anyhow!(
+                    "{answer} was already submitted, it is {}",
+                    guess.verdict
+                ));
+            }
+            let (Some(value), Ok(guessed)) = (value, guess.answer.parse::<i128>()) else {
+                continue;
+            };
+            let out_of_bounds = match guess.verdict {
+                Verdict::TooHigh => value >= guessed,
+                Verdict::TooLow => value <= guessed,
+                _ => false,
+            };
+            if out_of_bounds {
+                return Err(anyhow!(
+                    "{answer} can't be right, {guessed} was already {}",
+                    guess.verdict
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    /// Remember the site's response to a submission made at `now`
+    pub fn record(&mut self, part: Part, answer: &str, response: &Response, now: u64) {
+        if let Outcome::Judged(verdict) = response.outcome {
+            self.part_mut(part).push(Guess {
+                answer: answer.to_string(),
+                verdict,
+                at: now,
+            });
+        }
+        self.wait_until = response.cooldown.map(|cooldown| now + cooldown.as_secs());
+    }
+}
+
+pub fn unix_now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("clock is after 1970")
+        .as_secs()
+}
+
+/// Submit the answer for one part of a day whose files live in `dir`.
+/// A correct answer is also recorded in answers.toml.
+pub fn submit(
+    client: &Client,
+    year: u16,
+    day: u8,
+    dir: &Path,
+    part: Part,
+    answer: &Answer,
+) -> Result<Outcome> {
+    let answer_text = answer.to_string();
+    let mut guesses = Guesses::load(dir)?;
+    guesses.check(part, &answer_text, unix_now())?;
+
+    let html = client.submit(year, day, part, &answer_text)?;
+    let response = parse_response(&html)?;
+    guesses.record(part, &answer_text, &response, unix_now());
+    guesses.save(dir)?;
+
+    if response.outcome == Outcome::Judged(Verdict::Correct) {
+        let mut known = KnownAnswers::load(dir)?;
+        known.set("input", part, answer.clone());
+        known.save(dir)?;
+    }
+    Ok(response.outcome)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::mock::Server;
+
+    fn page(message: &str) -> String {
+        format!("<html><body><main>\n<article><p>{message}</p></article>\n</main></body></html>")
+    }
+
+    #[test]
+    fn test_parse_response() {
+        let correct = page("That's the right answer!  You are <span class=\"day-success\">one gold star</span> closer.");
+        assert_eq!(
+            parse_response(&correct).unwrap(),
+            Response {
+                outcome: Outcome::Judged(Verdict::Correct),
+                cooldown: None
+            }
+        );
+
+        let high = page("That's not the right answer; your answer is too high.  If you're stuck, make sure you're using the full input data. Please wait one minute before trying again. <a href=\"/2023/day/5\">[Return to Day 5]</a>");
+        assert_eq!(
+            parse_response(&high).unwrap(),
+            Response {
+                outcome: Outcome::Judged(Verdict::TooHigh),
+                cooldown: Some(Duration::from_secs(60))
+            }
+        );
+
+        let low = page("That's not the right answer; your answer is too low.  Because you have guessed incorrectly 4 times on this puzzle, please wait 5 minutes before trying again.");
+        assert_eq!(
+            parse_response(&low).unwrap(),
+            Response {
+                outcome: Outcome::Judged(Verdict::TooLow),
+                cooldown: Some(Duration::from_secs(300))
+            }
+        );
+
+        let wrong = page("That's not the right answer.  If you're stuck, make sure you're using the full input data; please wait one minute before trying again.");
+        assert_eq!(
+            parse_response(&wrong).unwrap().outcome,
+            Outcome::Judged(Verdict::Wrong)
+        );
+
+        let wait = page("You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 4m 3s left to wait.");
+        assert_eq!(
+            parse_response(&wait).unwrap(),
+            Response {
+                outcome: Outcome::Wait(Duration::from_secs(243)),
+                cooldown: Some(Duration::from_secs(243))
+            }
+        );
+
+        let solved = page("You don't seem to be solving the right level.  Did you already complete it? <a href=\"/2023/day/5\">[Return to Day 5]</a>");
+        assert_eq!(
+            parse_response(&solved).unwrap().outcome,
+            Outcome::AlreadySolved
+        );
+
+        assert!(parse_response(&page("Something else entirely")).is_err());
+    }
+
+    #[test]
+    fn test_check_guesses() {
+        let mut guesses = Guesses::default();
+        let judged = |verdict| Response {
+            outcome: Outcome::Judged(verdict),
+            cooldown: Some(Duration::from_secs(60)),
+        };
+        guesses.record(Part::A, "100", &judged(Verdict::TooHigh), 1000);
+        assert!(guesses.check(Part::A, "50", 1030).is_err(), "cooldown");
+        assert!(guesses.check(Part::A, "50", 1060).is_ok());
+        assert!(guesses.check(Part::A, "100", 1060).is_err(), "known wrong");
+        assert!(
+            guesses.check(Part::A, "120", 1060).is_err(),
+            "above too high"
+        );
+        assert!(guesses.check(Part::B, "120", 1060).is_ok(), "other part");
+
+        guesses.record(Part::A, "20", &judged(Verdict::TooLow), 2000);
+        assert!(guesses.check(Part::A, "10", 3000).is_err(), "below too low");
+        assert!(guesses.check(Part::A, "abc", 3000).is_ok());
+
+        guesses.record(Part::A, "42", &judged(Verdict::Correct), 4000);
+        assert!(
+            guesses.check(Part::A, "43", 5000).is_err(),
+            "already solved"
+        );
+    }
+
+    #[test]
+    fn test_submit() {
+        let dir = std::env::temp_dir().join(format!("aoc-submit-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        let server = Server::start(vec![
+            (200, page("That's not the right answer; your answer is too low. Please wait one minute before trying again.")),
+            (200, page("That's the right answer!")),
+        ]);
+        let client = Client::new(&server.config()).unwrap();
+
+        let outcome = submit(&client, 2023, 5, &dir, Part::B, &Answer::from(10)).unwrap();
+        assert_eq!(outcome, Outcome::Judged(Verdict::TooLow));
+        let request = server.request();
+        assert!(request.starts_with("POST /2023/day/5/answer "));
+        assert!(request.ends_with("level=2&answer=10"));
+
+        // the cooldown is still running, so nothing is sent
+        assert!(submit(&client, 2023, 5, &dir, Part::B, &Answer::from(11)).is_err());
+        let mut guesses = Guesses::load(&dir).unwrap();
+        assert_eq!(guesses.b.len(), 1);
+        guesses.wait_until = None;
+        guesses.save(&dir).unwrap();
+
+        let outcome = submit(&client, 2023, 5, &dir, Part::B, &Answer::from(11)).unwrap();
+        assert_eq!(outcome, Outcome::Judged(Verdict::Correct));
+        assert!(server.request().ends_with("level=2&answer=11"));
+        let known = KnownAnswers::load(&dir).unwrap();
+        assert_eq!(known.get("input", Part::B), Some(&Answer::from(11)));
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
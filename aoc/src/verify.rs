@@ -0,0 +1,148 @@
+//! `aoc verify`: rerun every solver against the answers recorded in each
+//! day's answers.toml.
+
+use std::fmt;
+use std::fs;
+
+use anyhow::{anyhow, Result};
+use aoc_common::examples::{self, EXAMPLES_DIR};
+use aoc_common::{Answer, KnownAnswers, Part};
+
+use crate::days::Day;
+
+/// Input files that are checked even when answers.toml doesn't mention them
+const DEFAULT_INPUTS: [&str; 1] = ["input"];
+
+enum Outcome {
+    Pass,
+    Fail { expected: Answer },
+    Unknown,
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Outcome::Pass => write!(f, "pass"),
+            Outcome::Fail { .. } => write!(f, "FAIL"),
+            Outcome::Unknown => write!(f, "unknown"),
+        }
+    }
+}
+
+#[derive(Default)]
+pub struct Summary {
+    pass: usize,
+    fail: usize,
+    unknown: usize,
+}
+
+impl Summary {
+    pub fn check(&self) -> Result<()> {
+        println!(
+            "{} passed, {} failed, {} unknown",
+            self.pass, self.fail, self.unknown
+        );
+        match self.fail {
+            0 => Ok(()),
+            n => Err(anyhow!("{n} answers failed to run or did not match")),
+        }
+    }
+}
+
+/// Verify one day, and its examples. With `record`, answers that weren't known yet are
+/// saved to answers.toml. Inputs that don't parse fail their parts rather than the day.
+pub fn verify_day(day: &Day, record: bool, summary: &mut Summary) -> Result<()> {
+    let dir = day.dir();
+    let mut known = KnownAnswers::load(&dir)?;
+    let mut input_names = known.input_names().map(str::to_string).collect::<Vec<_>>();
+    for name in DEFAULT_INPUTS {
+        if !input_names.iter().any(|n| n == name) {
+            input_names.push(name.to_string());
+        }
+    }
+
+    let mut recorded = false;
+    for name in input_names {
+        let input = match fs::read_to_string(dir.join(&name)) {
+            Ok(input) if !input.trim().is_empty() => input,
+            _ => {
+                if known.input_names().any(|n| n == name) {
+                    println!("Day {:02} {name:<14}: skipped, no input file", day.number);
+                }
+                continue;
+            }
+        };
+        for part in [Part::A, Part::B] {
+            let answer = match day.run(&input, &dir.join(&name).display().to_string(), part) {
+                Ok(answer) => answer,
+                Err(err) => {
+                    failed(day, &name, part, &err, summary);
+                    continue;
+                }
+            };
+            let outcome = check(day, &name, part, &answer, known.get(&name, part), summary);
+            if record && matches!(outcome, Outcome::Unknown) {
+                known.set(&name, part, answer);
+                recorded = true;
+            }
+        }
+    }
+
+    for example in examples::list(&dir)? {
+        let input = fs::read_to_string(&example.input)?;
+        let name = format!("{EXAMPLES_DIR}/{}", example.name);
+        for part in [Part::A, Part::B] {
+            if let Some(expected) = example.answers.get(part) {
+                match day.run(&input, &example.input.display().to_string(), part) {
+                    Ok(answer) => {
+                        check(day, &name, part, &answer, Some(expected), summary);
+                    }
+                    Err(err) => failed(day, &name, part, &err, summary),
+                }
+            }
+        }
+    }
+    if recorded {
+        known.save(&dir)?;
+    }
+    Ok(())
+}
+
+fn check(
+    day: &Day,
+    name: &str,
+    part: Part,
+    answer: &Answer,
+    expected: Option<&Answer>,
+    summary: &mut Summary,
+) -> Outcome {
+    let outcome = match expected {
+        Some(expected) if expected == answer => Outcome::Pass,
+        Some(expected) => Outcome::Fail {
+            expected: expected.clone(),
+        },
+        None => Outcome::Unknown,
+    };
+    match &outcome {
+        Outcome::Pass => summary.pass += 1,
+        Outcome::Fail { .. } => summary.fail += 1,
+        Outcome::Unknown => summary.unknown += 1,
+    }
+    match &outcome {
+        Outcome::Fail { expected } => println!(
+            "Day {:02} {name:<14} {part}: {outcome} expected {expected}, got {answer}",
+            day.number
+        ),
+        _ => println!(
+            "Day {:02} {name:<14} {part}: {outcome} {answer}",
+            day.number
+        ),
+    }
+    outcome
+}
+
+/// Count a part that couldn't be run as a failure, so the other days still get checked
+fn failed(day: &Day, name: &str, part: Part, err: &anyhow::Error, summary: &mut Summary) {
+    summary.fail += 1;
+    println!("Day {:02} {name:<14} {part}: FAIL {err:#}", day.number);
+}
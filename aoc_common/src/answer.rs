@@ -0,0 +1,118 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+/// The result of one part of a puzzle.
+///
+/// Answers compare by value rather than by variant: `Answer::I64(5)`, `Answer::U64(5)`
+/// and `Answer::Str("5")` are all equal, the same way the website would accept any of them.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum Answer {
+    I64(i64),
+    U64(u64),
+    I128(i128),
+    Str(String),
+}
+
+impl Answer {
+    fn as_i128(&self) -> Option<i128> {
+        match self {
+            Answer::I64(n) => Some(*n as i128),
+            Answer::U64(n) => Some(*n as i128),
+            Answer::I128(n) => Some(*n),
+            Answer::Str(s) => s.trim().parse().ok(),
+        }
+    }
+}
+
+impl PartialEq for Answer {
+    fn eq(&self, other: &Self) -> bool {
+        match (self.as_i128(), other.as_i128()) {
+            (Some(a), Some(b)) => a == b,
+            _ => self.to_string() == other.to_string(),
+        }
+    }
+}
+
+impl Eq for Answer {}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::I64(n) => write!(f, "{n}"),
+            Answer::U64(n) => write!(f, "{n}"),
+            Answer::I128(n) => write!(f, "{n}"),
+            Answer::Str(s) => write!(f, "{s}"),
+        }
+    }
+}
+
+impl From<i32> for Answer {
+    fn from(value: i32) -> Self {
+        Answer::I64(value as i64)
+    }
+}
+
+impl From<i64> for Answer {
+    fn from(value: i64) -> Self {
+        Answer::I64(value)
+    }
+}
+
+impl From<u32> for Answer {
+    fn from(value: u32) -> Self {
+        Answer::U64(value as u64)
+    }
+}
+
+impl From<u64> for Answer {
+    fn from(value: u64) -> Self {
+        Answer::U64(value)
+    }
+}
+
+impl From<usize> for Answer {
+    fn from(value: usize) -> Self {
+        Answer::U64(value as u64)
+    }
+}
+
+impl From<i128> for Answer {
+    fn from(value: i128) -> Self {
+        Answer::I128(value)
+    }
+}
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Str(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::Str(value.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_compare_across_variants() {
+        assert_eq!(Answer::from(5), Answer::from(5u64));
+        assert_eq!(Answer::from(5i128), Answer::from("5"));
+        assert_ne!(Answer::from(-5), Answer::from(5u64));
+        assert_eq!(Answer::from("abc"), Answer::from("abc"));
+        assert_ne!(Answer::from("abc"), Answer::from(0));
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(Answer::from(i128::MAX).to_string(), i128::MAX.to_string());
+        assert_eq!(Answer::from(-12).to_string(), "-12");
+        assert_eq!(Answer::from("EZ").to_string(), "EZ");
+    }
+}
@@ -0,0 +1,76 @@
+//! Property tests that check a day's solver against a slower one that is easier to trust.
+//!
+//! [`check`] runs like a `proptest!` block: random inputs until the two disagree, then
+//! shrinking down to the smallest input they still disagree on. That input is then saved
+//! as the day's next example, answered by the trusted solver, so the example tests keep
+//! failing on it until the solver is fixed.
+
+use std::path::Path;
+
+use proptest::strategy::Strategy;
+use proptest::test_runner::{Config, TestCaseError, TestError, TestRunner};
+
+use crate::examples;
+use crate::known::PartAnswers;
+use crate::Part;
+
+/// What to check on each value the strategy comes up with
+pub struct Differential<I, T, S> {
+    /// The puzzle input for the value, saved if the solvers disagree on it
+    pub input: I,
+    /// The answers to trust
+    pub trusted: T,
+    /// The answers to check, for the parts `trusted` answers
+    pub tested: S,
+}
+
+/// Check `differential` on values from `strategy`, saving the smallest failing input as
+/// an example of `day_dir` and panicking if there is one
+pub fn check<V, I, T, S>(
+    day_dir: &Path,
+    strategy: impl Strategy<Value = V>,
+    differential: Differential<I, T, S>,
+) where
+    I: Fn(&V) -> String,
+    T: Fn(&V) -> PartAnswers,
+    S: Fn(&V) -> PartAnswers,
+{
+    let Differential {
+        input,
+        trusted,
+        tested,
+    } = differential;
+    // the counterexamples go to the examples instead of proptest's own regression files
+    let config = Config {
+        failure_persistence: None,
+        ..Config::default()
+    };
+    let result = TestRunner::new(config).run(&strategy, |value| {
+        let (trusted, tested) = (trusted(&value), tested(&value));
+        for part in [Part::A, Part::B] {
+            if let Some(expected) = trusted.get(part) {
+                match tested.get(part) {
+                    Some(answer) if answer == expected => (),
+                    Some(answer) => {
+                        return Err(TestCaseError::fail(format!(
+                            "part {part}: expected {expected}, got {answer}"
+                        )))
+                    }
+                    None => return Err(TestCaseError::fail(format!("part {part} has no answer"))),
+                }
+            }
+        }
+        Ok(())
+    });
+    match result {
+        Ok(()) => (),
+        Err(TestError::Fail(reason, value)) => {
+            let input = input(&value);
+            let saved = examples::add(day_dir, &input, &trusted(&value)).unwrap_or_else(|err| {
+                panic!("{reason}, and could not save the input: {err}\n{input}")
+            });
+            panic!("{reason} on\n{input}\nsaved as {}", saved.display());
+        }
+        Err(TestError::Abort(reason)) => panic!("{reason}"),
+    }
+}
@@ -0,0 +1,234 @@
+//! Example inputs from the puzzle descriptions.
+//!
+//! Each example is stored as `examples/NN.txt` in the day's directory, with
+//! the answers the description gives for it in `examples/NN.answers.toml`:
+//!
+//! ```toml
+//! a = 136
+//! b = 64
+//! ```
+//!
+//! Every day's build.rs calls [`write_tests`], and the crate pulls the
+//! generated tests in with [`example_tests!`](crate::example_tests), so each
+//! answer in an answers file becomes a `#[test]` of its own.
+
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
+use std::{env, fs, io};
+
+use crate::known::PartAnswers;
+use crate::{Answer, Part};
+
+pub const EXAMPLES_DIR: &str = "examples";
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Example {
+    /// File stem, "01" for examples/01.txt
+    pub name: String,
+    pub input: PathBuf,
+    pub answers: PartAnswers,
+}
+
+/// The input of example `number`, examples/NN.txt
+pub fn input_path(day_dir: &Path, number: u32) -> PathBuf {
+    day_dir.join(EXAMPLES_DIR).join(format!("{number:02}.txt"))
+}
+
+/// examples/NN.txt -> examples/NN.answers.toml
+pub fn answers_path(input: &Path) -> PathBuf {
+    input.with_extension("answers.toml")
+}
+
+/// The number the next new example of `day_dir` gets, one past the highest so far
+pub fn next_number(day_dir: &Path) -> io::Result<u32> {
+    let entries = match fs::read_dir(day_dir.join(EXAMPLES_DIR)) {
+        Ok(entries) => entries,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(1),
+        Err(e) => return Err(e),
+    };
+    let mut highest = 0;
+    for entry in entries {
+        let path = entry?.path();
+        if path.extension().is_some_and(|ext| ext == "txt") {
+            let number = path
+                .file_stem()
+                .and_then(|stem| stem.to_str()?.parse().ok());
+            highest = highest.max(number.unwrap_or(0));
+        }
+    }
+    Ok(highest + 1)
+}
+
+/// Save `input` as a new example of `day_dir` with its `answers`
+pub fn add(day_dir: &Path, input: &str, answers: &PartAnswers) -> io::Result<PathBuf> {
+    let path = input_path(day_dir, next_number(day_dir)?);
+    fs::create_dir_all(day_dir.join(EXAMPLES_DIR))?;
+    fs::write(&path, input)?;
+    save_answers(&answers_path(&path), answers)?;
+    Ok(path)
+}
+
+pub fn load_answers(path: &Path) -> io::Result<PartAnswers> {
+    let contents = fs::read_to_string(path)?;
+    toml::from_str(&contents).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+}
+
+pub fn save_answers(path: &Path, answers: &PartAnswers) -> io::Result<()> {
+    let contents =
+        toml::to_string(answers).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+    fs::write(path, contents)
+}
+
+/// Every example in `day_dir` that has an answers file, in name order.
+/// A day without an examples directory has no examples.
+pub fn list(day_dir: &Path) -> io::Result<Vec<Example>> {
+    let entries = match fs::read_dir(day_dir.join(EXAMPLES_DIR)) {
+        Ok(entries) => entries,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(e),
+    };
+    let mut examples = Vec::new();
+    for entry in entries {
+        let input = entry?.path();
+        if input.extension().is_none_or(|ext| ext != "txt") {
+            continue;
+        }
+        let answers_path = answers_path(&input);
+        if !answers_path.exists() {
+            continue;
+        }
+        let name = input
+            .file_stem()
+            .and_then(|stem| stem.to_str())
+            .unwrap_or_default()
+            .to_string();
+        examples.push(Example {
+            name,
+            answers: load_answers(&answers_path)?,
+            input,
+        });
+    }
+    examples.sort_by(|a, b| a.name.cmp(&b.name));
+    Ok(examples)
+}
+
+/// File in `OUT_DIR` that [`write_tests`] generates
+pub const TESTS_FILE: &str = "example_tests.rs";
+
+/// Generate a `#[test]` per part of every example of the crate being built.
+/// Meant to be called from build.rs.
+pub fn write_tests() {
+    let manifest_dir = PathBuf::from(env::var_os("CARGO_MANIFEST_DIR").expect("run from build.rs"));
+    let out_dir = PathBuf::from(env::var_os("OUT_DIR").expect("run from build.rs"));
+    println!(
+        "cargo:rerun-if-changed={}",
+        manifest_dir.join(EXAMPLES_DIR).display()
+    );
+
+    let examples = list(&manifest_dir).expect("could not read the examples");
+    for example in &examples {
+        println!("cargo:rerun-if-changed={}", example.input.display());
+        println!(
+            "cargo:rerun-if-changed={}",
+            answers_path(&example.input).display()
+        );
+    }
+    fs::write(out_dir.join(TESTS_FILE), tests_source(&examples))
+        .expect("could not write the example tests");
+}
+
+fn tests_source(examples: &[Example]) -> String {
+    let mut source = String::new();
+    for example in examples {
+        for part in [Part::A, Part::B] {
+            let Some(answer) = example.answers.get(part) else {
+                continue;
+            };
+            let expected = match answer {
+                Answer::I64(n) => format!("{n}i64"),
+                Answer::U64(n) => format!("{n}u64"),
+                Answer::I128(n) => format!("{n}i128"),
+                Answer::Str(s) => format!("{s:?}"),
+            };
+            writeln!(
+                source,
+                "#[test]
+fn example_{name}_{lower}() {{
+    let input = include_str!({input:?});
+    let answer = <crate::Puzzle as ::aoc_common::Solution>::solve(input, ::aoc_common::Part::{part})
+        .unwrap_or_else(|err| panic!(\"{{}}\", err.render(input)));
+    assert_eq!(answer, ::aoc_common::Answer::from({expected}));
+}}",
+                name = example
+                    .name
+                    .replace(|c: char| !c.is_ascii_alphanumeric(), "_"),
+                lower = part.to_string().to_lowercase(),
+                input = example.input,
+            )
+            .unwrap();
+        }
+    }
+    source
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_list() {
+        let dir = std::env::temp_dir().join(format!("aoc-examples-{}", std::process::id()));
+        let examples_dir = dir.join(EXAMPLES_DIR);
+        fs::create_dir_all(&examples_dir).unwrap();
+        fs::write(examples_dir.join("02.txt"), "2\n").unwrap();
+        fs::write(examples_dir.join("02.answers.toml"), "b = \"x\"\n").unwrap();
+        fs::write(examples_dir.join("01.txt"), "1\n").unwrap();
+        fs::write(examples_dir.join("01.answers.toml"), "a = 5\n").unwrap();
+        // no answers, not an example
+        fs::write(examples_dir.join("03.txt"), "3\n").unwrap();
+
+        let examples = list(&dir).unwrap();
+        assert_eq!(
+            examples.iter().map(|e| e.name.as_str()).collect::<Vec<_>>(),
+            ["01", "02"]
+        );
+        assert_eq!(examples[0].answers.get(Part::A), Some(&Answer::from(5)));
+        assert_eq!(examples[1].answers.get(Part::A), None);
+        assert_eq!(examples[1].answers.get(Part::B), Some(&Answer::from("x")));
+        assert_eq!(examples[1].input, examples_dir.join("02.txt"));
+
+        // numbered after 03.txt, even though that one has no answers
+        let answers = PartAnswers {
+            a: Some(Answer::from(4)),
+            b: None,
+        };
+        assert_eq!(next_number(&dir).unwrap(), 4);
+        assert_eq!(
+            add(&dir, "4\n", &answers).unwrap(),
+            examples_dir.join("04.txt")
+        );
+        assert_eq!(list(&dir).unwrap()[2].answers, answers);
+
+        fs::remove_dir_all(&dir).unwrap();
+        assert_eq!(list(&dir).unwrap(), []);
+        assert_eq!(next_number(&dir).unwrap(), 1);
+    }
+
+    #[test]
+    fn test_tests_source() {
+        let example = Example {
+            name: "01".to_string(),
+            input: PathBuf::from("/days/puzzle_14/examples/01.txt"),
+            answers: PartAnswers {
+                a: None,
+                b: Some(Answer::from(64)),
+            },
+        };
+        let source = tests_source(&[example]);
+        assert!(!source.contains("fn example_01_a"));
+        assert!(source.contains("fn example_01_b()"));
+        assert!(source.contains("include_str!(\"/days/puzzle_14/examples/01.txt\")"));
+        assert!(source.contains("::aoc_common::Part::B"));
+        assert!(source.contains("::aoc_common::Answer::from(64i64)"));
+    }
+}
@@ -0,0 +1,97 @@
+//! Random but valid puzzle inputs, for stress tests and fuzzing.
+//!
+//! Every day has a `gen` module whose `generate(rng, size)` writes an input in that
+//! day's format. What `size` counts is up to the day: lines, the side of a grid,
+//! patterns and so on. The same seed and size always give the same input.
+
+use std::collections::HashSet;
+use std::hash::Hash;
+
+use rand::SeedableRng;
+
+pub use rand::seq::{IndexedRandom, SliceRandom};
+pub use rand::{Rng, RngExt};
+
+/// The generator behind `aoc gen --seed`: its output is fixed by the algorithm, not by
+/// the platform or the rand release
+pub type Seeded = rand::rngs::Xoshiro256PlusPlus;
+
+pub fn seeded(seed: u64) -> Seeded {
+    Seeded::seed_from_u64(seed)
+}
+
+/// `count` different values from `draw`, in the order they came up. Loops forever if
+/// `draw` can't come up with that many.
+pub fn distinct<R, T, F>(rng: &mut R, count: usize, mut draw: F) -> Vec<T>
+where
+    R: Rng + ?Sized,
+    T: Clone + Eq + Hash,
+    F: FnMut(&mut R) -> T,
+{
+    let mut seen = HashSet::new();
+    let mut values = Vec::with_capacity(count);
+    while values.len() < count {
+        let value = draw(rng);
+        if seen.insert(value.clone()) {
+            values.push(value);
+        }
+    }
+    values
+}
+
+/// Cut `0..total` into `pieces` non-empty ranges, in order
+pub fn split<R: Rng + ?Sized>(rng: &mut R, total: u64, pieces: usize) -> Vec<std::ops::Range<u64>> {
+    assert!(
+        pieces > 0 && pieces as u64 <= total,
+        "can't cut {total} into {pieces} pieces"
+    );
+    let mut cuts = rand::seq::index::sample(rng, total as usize - 1, pieces.saturating_sub(1))
+        .into_iter()
+        .map(|i| i as u64 + 1)
+        .collect::<Vec<_>>();
+    cuts.sort();
+    let mut ends = cuts;
+    ends.push(total);
+    let mut start = 0;
+    ends.into_iter()
+        .map(|end| {
+            let range = start..end;
+            start = end;
+            range
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_seeded() {
+        let draw = |seed| {
+            let mut rng = seeded(seed);
+            (0..5)
+                .map(|_| rng.random_range(0..1000))
+                .collect::<Vec<u32>>()
+        };
+        assert_eq!(draw(7), draw(7));
+        assert_ne!(draw(7), draw(8));
+    }
+
+    #[test]
+    fn test_distinct_and_split() {
+        let mut rng = seeded(1);
+        let mut values = distinct(&mut rng, 10, |rng| rng.random_range(0..10));
+        values.sort();
+        assert_eq!(values, (0..10).collect::<Vec<_>>());
+
+        for pieces in 1..=20 {
+            let ranges = split(&mut rng, 20, pieces);
+            assert_eq!(ranges.len(), pieces);
+            assert_eq!(ranges[0].start, 0);
+            assert_eq!(ranges[pieces - 1].end, 20);
+            assert!(ranges.iter().all(|range| !range.is_empty()));
+            assert!(ranges.windows(2).all(|pair| pair[0].end == pair[1].start));
+        }
+    }
+}
@@ -0,0 +1,133 @@
+//! The answers.toml registry of known answers for a day.
+//!
+//! ```toml
+//! [input]
+//! a = 35
+//! b = 10834440
+//!
+//! [sample_input]
+//! a = 35
+//! ```
+
+use std::collections::BTreeMap;
+use std::path::Path;
+use std::{fs, io};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{Answer, Part};
+
+pub const ANSWERS_FILE: &str = "answers.toml";
+
+/// Known answers for each input file of a day, keyed by file name
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct KnownAnswers(BTreeMap<String, PartAnswers>);
+
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PartAnswers {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub a: Option<Answer>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub b: Option<Answer>,
+}
+
+impl PartAnswers {
+    pub fn get(&self, part: Part) -> Option<&Answer> {
+        match part {
+            Part::A => self.a.as_ref(),
+            Part::B => self.b.as_ref(),
+        }
+    }
+
+    pub fn set(&mut self, part: Part, answer: Answer) {
+        match part {
+            Part::A => self.a = Some(answer),
+            Part::B => self.b = Some(answer),
+        }
+    }
+}
+
+impl KnownAnswers {
+    /// Load `answers.toml` from a day's directory. A missing file means no answers are known yet.
+    pub fn load(dir: &Path) -> io::Result<Self> {
+        match fs::read_to_string(dir.join(ANSWERS_FILE)) {
+            Ok(contents) => Self::from_toml(&contents),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(e),
+        }
+    }
+
+    pub fn from_toml(contents: &str) -> io::Result<Self> {
+        toml::from_str(contents).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+    }
+
+    pub fn save(&self, dir: &Path) -> io::Result<()> {
+        let contents =
+            toml::to_string(self).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        fs::write(dir.join(ANSWERS_FILE), contents)
+    }
+
+    pub fn get(&self, input_name: &str, part: Part) -> Option<&Answer> {
+        self.0.get(input_name)?.get(part)
+    }
+
+    pub fn set(&mut self, input_name: &str, part: Part, answer: Answer) {
+        self.0
+            .entry(input_name.to_string())
+            .or_default()
+            .set(part, answer.for_toml());
+    }
+
+    pub fn input_names(&self) -> impl Iterator<Item = &str> {
+        self.0.keys().map(String::as_str)
+    }
+}
+
+impl Answer {
+    /// TOML integers are i64, anything bigger has to be stored as a string
+    fn for_toml(self) -> Answer {
+        match self {
+            Answer::U64(n) if n > i64::MAX as u64 => Answer::Str(n.to_string()),
+            Answer::U64(n) => Answer::I64(n as i64),
+            Answer::I128(n) => match i64::try_from(n) {
+                Ok(n) => Answer::I64(n),
+                Err(_) => Answer::Str(n.to_string()),
+            },
+            other => other,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let known = KnownAnswers::from_toml(
+            "[input]\nb = 10834440\n\n[sample_input]\na = 35\nb = \"46\"\n",
+        )
+        .unwrap();
+        assert_eq!(known.get("input", Part::A), None);
+        assert_eq!(known.get("input", Part::B), Some(&Answer::from(10834440)));
+        assert_eq!(known.get("sample_input", Part::A), Some(&Answer::from(35)));
+        assert_eq!(known.get("sample_input", Part::B), Some(&Answer::from(46)));
+        assert_eq!(known.get("other", Part::A), None);
+        assert_eq!(
+            known.input_names().collect::<Vec<_>>(),
+            ["input", "sample_input"]
+        );
+    }
+
+    #[test]
+    fn test_roundtrip() {
+        let mut known = KnownAnswers::default();
+        known.set("input", Part::A, Answer::from(12u64));
+        known.set("input", Part::B, Answer::from(u64::MAX));
+        known.set("sample_input", Part::B, Answer::from(-3i128));
+        let contents = toml::to_string(&known).unwrap();
+        assert_eq!(KnownAnswers::from_toml(&contents).unwrap(), known);
+        assert_eq!(known.get("input", Part::B), Some(&Answer::from(u64::MAX)));
+    }
+}
@@ -0,0 +1,28 @@
+//! Code shared by every puzzle_NN crate and the aoc runner.
+
+mod answer;
+#[cfg(feature = "proptest")]
+pub mod differential;
+pub mod examples;
+pub mod gen;
+pub mod known;
+pub mod math;
+pub mod parse;
+pub mod simulate;
+mod solution;
+
+pub use answer::Answer;
+pub use known::KnownAnswers;
+pub use parse::ParseError;
+pub use solution::{Part, Solution};
+
+/// Include the example tests that build.rs generated with [`examples::write_tests`]
+#[macro_export]
+macro_rules! example_tests {
+    () => {
+        #[cfg(test)]
+        mod example_tests {
+            include!(concat!(env!("OUT_DIR"), "/example_tests.rs"));
+        }
+    };
+}
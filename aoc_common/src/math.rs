@@ -0,0 +1,232 @@
+//! Number theory for puzzles whose answer is "when do all these cycles line up".
+
+use std::fmt::Debug;
+use std::ops::{Add, Div, Mul, Neg, Rem, Sub};
+
+/// The signed integer types the functions below work over
+pub trait Integer:
+    Copy
+    + Ord
+    + Debug
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Rem<Output = Self>
+    + Neg<Output = Self>
+{
+    const ZERO: Self;
+    const ONE: Self;
+
+    fn rem_euclid(self, modulus: Self) -> Self;
+
+    fn checked_mul(self, other: Self) -> Option<Self>;
+
+    /// `self * other mod modulus` without overflowing, for a positive modulus
+    fn mul_mod(self, other: Self, modulus: Self) -> Self;
+}
+
+impl Integer for i64 {
+    const ZERO: Self = 0;
+    const ONE: Self = 1;
+
+    fn rem_euclid(self, modulus: Self) -> Self {
+        i64::rem_euclid(self, modulus)
+    }
+
+    fn checked_mul(self, other: Self) -> Option<Self> {
+        i64::checked_mul(self, other)
+    }
+
+    fn mul_mod(self, other: Self, modulus: Self) -> Self {
+        (self as i128 * other as i128).rem_euclid(modulus as i128) as i64
+    }
+}
+
+impl Integer for i128 {
+    const ZERO: Self = 0;
+    const ONE: Self = 1;
+
+    fn rem_euclid(self, modulus: Self) -> Self {
+        i128::rem_euclid(self, modulus)
+    }
+
+    fn checked_mul(self, other: Self) -> Option<Self> {
+        i128::checked_mul(self, other)
+    }
+
+    fn mul_mod(self, other: Self, modulus: Self) -> Self {
+        // nothing wider to lean on, so double-and-add
+        let add_mod = |x: i128, y: i128| {
+            if x >= modulus - y {
+                x - (modulus - y)
+            } else {
+                x + y
+            }
+        };
+        let mut a = self.rem_euclid(modulus);
+        let mut b = other.rem_euclid(modulus);
+        let mut product = 0;
+        while b > 0 {
+            if b & 1 == 1 {
+                product = add_mod(product, a);
+            }
+            a = add_mod(a, a);
+            b >>= 1;
+        }
+        product
+    }
+}
+
+fn abs<T: Integer>(n: T) -> T {
+    if n < T::ZERO {
+        -n
+    } else {
+        n
+    }
+}
+
+/// The greatest common divisor, never negative; `gcd(0, 0)` is 0
+pub fn gcd<T: Integer>(a: T, b: T) -> T {
+    let (mut a, mut b) = (abs(a), abs(b));
+    while b != T::ZERO {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+/// The least common multiple, never negative; 0 if either is 0.
+/// Panics if it doesn't fit in `T`.
+pub fn lcm<T: Integer>(a: T, b: T) -> T {
+    if a == T::ZERO || b == T::ZERO {
+        return T::ZERO;
+    }
+    abs(a / gcd(a, b))
+        .checked_mul(abs(b))
+        .unwrap_or_else(|| panic!("lcm({a:?}, {b:?}) overflows"))
+}
+
+/// `(g, x, y)` with `a * x + b * y == g == gcd(a, b)`
+pub fn extended_gcd<T: Integer>(a: T, b: T) -> (T, T, T) {
+    let (mut r0, mut r1) = (a, b);
+    let (mut x0, mut x1) = (T::ONE, T::ZERO);
+    let (mut y0, mut y1) = (T::ZERO, T::ONE);
+    while r1 != T::ZERO {
+        let q = r0 / r1;
+        (r0, r1) = (r1, r0 - q * r1);
+        (x0, x1) = (x1, x0 - q * x1);
+        (y0, y1) = (y1, y0 - q * y1);
+    }
+    if r0 < T::ZERO {
+        (-r0, -x0, -y0)
+    } else {
+        (r0, x0, y0)
+    }
+}
+
+/// Solve `x ≡ residue (mod modulus)` for every `(residue, modulus)` pair at once.
+///
+/// The moduli don't have to be coprime. Returns the smallest non-negative `x` and the lcm
+/// of the moduli, which every other solution differs from `x` by a multiple of, or `None`
+/// if the congruences contradict each other or that lcm doesn't fit in `T`. Panics on a
+/// modulus that isn't positive.
+pub fn crt<T: Integer>(congruences: &[(T, T)]) -> Option<(T, T)> {
+    let mut solution = (T::ZERO, T::ONE);
+    for &(residue, modulus) in congruences {
+        assert!(modulus > T::ZERO, "modulus {modulus:?} is not positive");
+        let (r1, m1) = solution;
+        let r2 = residue.rem_euclid(modulus);
+        // r1 + m1 * k ≡ r2 (mod modulus), solvable when gcd(m1, modulus) divides the gap
+        let (g, inverse, _) = extended_gcd(m1, modulus);
+        let gap = r2 - r1;
+        if gap % g != T::ZERO {
+            return None;
+        }
+        let step = modulus / g;
+        let combined = m1.checked_mul(step)?;
+        let k = (gap / g).mul_mod(inverse, step);
+        // k < step, so this stays below the combined modulus
+        solution = (r1 + m1 * k, combined);
+    }
+    Some(solution)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_examples() {
+        assert_eq!(gcd(12i64, -18), 6);
+        assert_eq!(gcd(0i64, 0), 0);
+        assert_eq!(lcm(4i64, 6), 12);
+        assert_eq!(lcm(0i64, 6), 0);
+        assert_eq!(extended_gcd(240i64, 46), (2, -9, 47));
+        assert_eq!(crt(&[(2i64, 3), (3, 5), (2, 7)]), Some((23, 105)));
+        assert_eq!(crt(&[(1i64, 4), (3, 6)]), Some((9, 12)));
+        assert_eq!(crt(&[(1i64, 4), (2, 6)]), None);
+        assert_eq!(crt::<i64>(&[]), Some((0, 1)));
+        // coprime, so their lcm is their product, which is past i64::MAX
+        assert_eq!(crt(&[(0i64, i64::MAX), (0, i64::MAX - 1)]), None);
+        assert_eq!(crt(&[(1i64, i64::MAX), (1, i64::MAX)]), Some((1, i64::MAX)));
+    }
+
+    #[test]
+    fn test_large() {
+        assert_eq!(lcm(1i64 << 61, 3 << 20), 3 << 61);
+        // the combined modulus fits in i64, the products on the way there don't
+        let moduli = [1_000_000_007i64, 998_244_353];
+        let x = 123_456_789_012_345_678i64;
+        let (solution, modulus) = crt(&moduli.map(|m| (x % m, m))).unwrap();
+        assert_eq!((solution, modulus), (x, 998_244_359_987_710_471));
+        assert_eq!(
+            u64::MAX as i128 * 3 % (i128::MAX - 1),
+            (u64::MAX as i128).mul_mod(3, i128::MAX - 1)
+        );
+        assert_eq!((i128::MAX - 2).mul_mod(i128::MAX - 2, i128::MAX), 4);
+    }
+
+    proptest! {
+        #[test]
+        fn prop_gcd_divides(a in -1_000_000i64..1_000_000, b in -1_000_000i64..1_000_000) {
+            let g = gcd(a, b);
+            prop_assert!(g >= 0);
+            if g != 0 {
+                prop_assert_eq!(a % g, 0);
+                prop_assert_eq!(b % g, 0);
+                prop_assert_eq!(gcd(a / g, b / g), 1);
+            }
+        }
+
+        #[test]
+        fn prop_lcm_is_multiple(a in 1i64..1_000_000, b in 1i64..1_000_000) {
+            let l = lcm(a, b);
+            prop_assert_eq!(l % a, 0);
+            prop_assert_eq!(l % b, 0);
+            prop_assert_eq!(l * gcd(a, b), a * b);
+        }
+
+        #[test]
+        fn prop_extended_gcd(a in any::<i64>().prop_map(|n| n >> 2), b in any::<i64>().prop_map(|n| n >> 2)) {
+            let (g, x, y) = extended_gcd(a as i128, b as i128);
+            prop_assert_eq!(g, gcd(a, b) as i128);
+            prop_assert_eq!(a as i128 * x + b as i128 * y, g);
+        }
+
+        #[test]
+        fn prop_crt_matches_search(congruences in prop::collection::vec((0i64..50, 1i64..12), 1..4)) {
+            let modulus = congruences.iter().fold(1, |acc, &(_, m)| lcm(acc, m));
+            let fits = |x: i64| congruences.iter().all(|&(r, m)| x % m == r % m);
+            let expected = (0..modulus).find(|&x| fits(x));
+            prop_assert_eq!(crt(&congruences), expected.map(|x| (x, modulus)));
+        }
+
+        #[test]
+        fn prop_crt_i128(x in 0i128..1 << 100, moduli in prop::collection::vec(1i128..1 << 40, 1..4)) {
+            let congruences = moduli.iter().map(|&m| (x % m, m)).collect::<Vec<_>>();
+            let (solution, modulus) = crt(&congruences).unwrap();
+            prop_assert_eq!(solution, x % modulus);
+        }
+    }
+}
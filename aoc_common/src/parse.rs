@@ -0,0 +1,312 @@
+//! Errors for malformed puzzle input, pointing at where in the input things went wrong,
+//! and the small parsers most days are built from.
+//!
+//! The parsers take the whole `input` next to the piece they parse, which should be a
+//! slice of it, so that errors can point at the right spot.
+
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+/// A parse failure at a location in the input
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// 1-based
+    pub line: usize,
+    /// 1-based, counted in chars
+    pub column: usize,
+    /// The offending text, empty when something is missing
+    pub token: String,
+    /// What should have been there, "a number", "L or R", ...
+    pub expected: String,
+}
+
+impl ParseError {
+    /// An error at `token`, which should be a slice of `input` so its position
+    /// is exact. Otherwise the first occurrence of `token` in `input` is blamed.
+    pub fn new(input: &str, token: &str, expected: impl Into<String>) -> Self {
+        let offset = offset_in(input, token)
+            .or_else(|| input.find(token))
+            .unwrap_or(input.len());
+        Self::at_offset(input, offset, token, expected.into())
+    }
+
+    /// An error for something missing right after `after`, a slice of `input`
+    pub fn missing(input: &str, after: &str, expected: impl Into<String>) -> Self {
+        let offset = offset_in(input, after).map_or(input.len(), |start| start + after.len());
+        Self::at_offset(input, offset, "", expected.into())
+    }
+
+    fn at_offset(input: &str, offset: usize, token: &str, expected: String) -> Self {
+        let before = &input[..offset];
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+        Self {
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+            token: token.to_string(),
+            expected,
+        }
+    }
+
+    /// A compiler-style report quoting the offending line of `input`, with the
+    /// token underlined
+    pub fn render(&self, input: &str) -> String {
+        let source = input.lines().nth(self.line - 1).unwrap_or_default();
+        let gutter = self.line.to_string().len();
+        let underline = "^".repeat(self.token.chars().count().max(1));
+        format!(
+            "error: expected {}, found {}\n{:gutter$}--> line {}, column {}\n{:gutter$} |\n{} | {}\n{:gutter$} | {:indent$}{underline}",
+            self.expected,
+            self.found(),
+            "",
+            self.line,
+            self.column,
+            "",
+            self.line,
+            source,
+            "",
+            "",
+            indent = self.column - 1,
+        )
+    }
+
+    fn found(&self) -> String {
+        match self.token.as_str() {
+            "" => "nothing".to_string(),
+            token => format!("\"{token}\""),
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: expected {}, found {}",
+            self.line,
+            self.column,
+            self.expected,
+            self.found()
+        )
+    }
+}
+
+impl Error for ParseError {}
+
+/// Byte offset of `part` in `whole`, if `part` is a slice of it
+fn offset_in(whole: &str, part: &str) -> Option<usize> {
+    let start = (part.as_ptr() as usize).checked_sub(whole.as_ptr() as usize)?;
+    (start + part.len() <= whole.len()).then_some(start)
+}
+
+/// Parse `token`, a slice of `input`, as a number
+pub fn number<T: FromStr>(input: &str, token: &str) -> Result<T, ParseError> {
+    token
+        .parse()
+        .map_err(|_| ParseError::new(input, token, "a number"))
+}
+
+/// The whitespace-separated numbers in `text`, a slice of `input`
+pub fn numbers<T: FromStr>(input: &str, text: &str) -> Result<Vec<T>, ParseError> {
+    text.split_whitespace()
+        .map(|token| number(input, token))
+        .collect()
+}
+
+/// Every integer in `text`, a slice of `input`, whatever separates them.
+/// A `-` directly before the digits makes the integer negative.
+pub fn integers<T: FromStr>(input: &str, text: &str) -> Result<Vec<T>, ParseError> {
+    let mut found = Vec::new();
+    let mut start = None;
+    for (i, c) in text.char_indices().chain([(text.len(), ' ')]) {
+        let digit = c.is_ascii_digit();
+        match start {
+            None if digit => {
+                start = Some(match text[..i].ends_with('-') {
+                    true => i - 1,
+                    false => i,
+                })
+            }
+            Some(from) if !digit => {
+                found.push(number(input, &text[from..i])?);
+                start = None;
+            }
+            _ => (),
+        }
+    }
+    Ok(found)
+}
+
+/// All the digits in `text`, a slice of `input`, read as one number with the
+/// whitespace between them ignored
+pub fn joined_number<T: FromStr>(input: &str, text: &str) -> Result<T, ParseError> {
+    let digits = text.split_whitespace().collect::<String>();
+    digits
+        .parse()
+        .map_err(|_| ParseError::new(input, text.trim(), "a number"))
+}
+
+/// The rest of `line` after `"label:"`
+pub fn labelled<'a>(input: &str, line: &'a str, label: &str) -> Result<&'a str, ParseError> {
+    line.trim_start()
+        .strip_prefix(label)
+        .and_then(|rest| rest.strip_prefix(':'))
+        .ok_or_else(|| ParseError::new(input, line, format!("\"{label}: ...\"")))
+}
+
+/// The numbers in `"label: 1 2 3"`
+pub fn labelled_numbers<T: FromStr>(
+    input: &str,
+    line: &str,
+    label: &str,
+) -> Result<Vec<T>, ParseError> {
+    numbers(input, labelled(input, line, label)?)
+}
+
+/// The number and the rest of `line` in `"label N: rest"`
+pub fn numbered<'a, T: FromStr>(
+    input: &str,
+    line: &'a str,
+    label: &str,
+) -> Result<(T, &'a str), ParseError> {
+    let expected = || format!("\"{label} N: ...\"");
+    let (head, rest) = line
+        .split_once(':')
+        .ok_or_else(|| ParseError::new(input, line, expected()))?;
+    let id = head
+        .trim_start()
+        .strip_prefix(label)
+        .filter(|id| id.starts_with(char::is_whitespace))
+        .ok_or_else(|| ParseError::new(input, head, expected()))?;
+    Ok((number(input, id.trim())?, rest))
+}
+
+/// The three parts of `"key = (left, right)"`
+pub fn key_pair<'a>(input: &str, line: &'a str) -> Result<(&'a str, &'a str, &'a str), ParseError> {
+    let (key, pair) = line
+        .split_once('=')
+        .ok_or_else(|| ParseError::new(input, line, "\"KEY = (LEFT, RIGHT)\""))?;
+    let pair = pair.trim();
+    let (left, right) = pair
+        .strip_prefix('(')
+        .and_then(|p| p.strip_suffix(')'))
+        .and_then(|p| p.split_once(','))
+        .ok_or_else(|| ParseError::new(input, pair, "\"(LEFT, RIGHT)\""))?;
+    Ok((key.trim(), left.trim(), right.trim()))
+}
+
+/// The lines of `input`, grouped into blocks separated by blank lines
+pub fn blocks(input: &str) -> Vec<Vec<&str>> {
+    let mut blocks = vec![];
+    let mut block = vec![];
+    for line in input.lines() {
+        if line.trim().is_empty() {
+            if !block.is_empty() {
+                blocks.push(std::mem::take(&mut block));
+            }
+        } else {
+            block.push(line);
+        }
+    }
+    if !block.is_empty() {
+        blocks.push(block);
+    }
+    blocks
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "Time: 7 15\nDistance: 9 4x0\n";
+
+    #[test]
+    fn test_location() {
+        let token = INPUT.split_whitespace().nth(5).unwrap();
+        let err = number::<u32>(INPUT, token).unwrap_err();
+        assert_eq!((err.line, err.column), (2, 13));
+        assert_eq!(err.token, "4x0");
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 13: expected a number, found \"4x0\""
+        );
+
+        // not a slice of the input, found by searching
+        let err = ParseError::new(INPUT, &String::from("15"), "a distance");
+        assert_eq!((err.line, err.column), (1, 9));
+
+        let first_line = INPUT.lines().next().unwrap();
+        let err = ParseError::missing(INPUT, first_line, "a third time");
+        assert_eq!((err.line, err.column), (1, 11));
+        assert_eq!(err.token, "");
+    }
+
+    #[test]
+    fn test_render() {
+        let token = &INPUT[23..26];
+        assert_eq!(token, "4x0");
+        let rendered = ParseError::new(INPUT, token, "a number").render(INPUT);
+        assert_eq!(
+            rendered,
+            "error: expected a number, found \"4x0\"
+ --> line 2, column 13
+  |
+2 | Distance: 9 4x0
+  |             ^^^"
+        );
+    }
+
+    #[test]
+    fn test_numbers() {
+        let input = "Time:      7  15   30\nDistance:  9  40  2OO\n";
+        let (time, distance) = input.split_once('\n').unwrap();
+        assert_eq!(
+            labelled_numbers::<u32>(input, time, "Time"),
+            Ok(vec![7, 15, 30])
+        );
+        assert_eq!(
+            joined_number::<u64>(input, labelled(input, time, "Time").unwrap()),
+            Ok(71530)
+        );
+        let err = labelled_numbers::<u32>(input, distance, "Distance").unwrap_err();
+        assert_eq!((err.line, err.column, err.token.as_str()), (2, 19, "2OO"));
+        let err = labelled(input, distance, "Time").unwrap_err();
+        assert_eq!(
+            (err.line, err.column, err.expected.as_str()),
+            (2, 1, "\"Time: ...\"")
+        );
+
+        let input = "x=-3, y=12..-4 and 7";
+        assert_eq!(integers::<i32>(input, input), Ok(vec![-3, 12, -4, 7]));
+        let err = integers::<u8>(input, input).unwrap_err();
+        assert_eq!((err.column, err.token.as_str()), (3, "-3"));
+    }
+
+    #[test]
+    fn test_records() {
+        let input = "Card  12: 41 48 | 83 86\nCard x: 1\nAAA = (BBB, CCC)\nAAA = BBB\n";
+        let lines = input.lines().collect::<Vec<_>>();
+        assert_eq!(
+            numbered::<u32>(input, lines[0], "Card"),
+            Ok((12, " 41 48 | 83 86"))
+        );
+        let err = numbered::<u32>(input, lines[1], "Card").unwrap_err();
+        assert_eq!((err.line, err.column, err.token.as_str()), (2, 6, "x"));
+        let err = numbered::<u32>(input, lines[2], "Card").unwrap_err();
+        assert_eq!((err.line, err.column), (3, 1));
+
+        assert_eq!(key_pair(input, lines[2]), Ok(("AAA", "BBB", "CCC")));
+        let err = key_pair(input, lines[3]).unwrap_err();
+        assert_eq!((err.line, err.column, err.token.as_str()), (4, 7, "BBB"));
+    }
+
+    #[test]
+    fn test_blocks() {
+        let input = "\na\nb\n\n  \nc\n\n";
+        assert_eq!(blocks(input), [vec!["a", "b"], vec!["c"]]);
+        assert!(blocks("").is_empty());
+        // still slices of the input, so errors point at the right line
+        let c = blocks(input)[1][0];
+        assert_eq!(ParseError::new(input, c, "d").line, 6);
+    }
+}
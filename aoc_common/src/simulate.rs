@@ -0,0 +1,118 @@
+//! Run a step function a huge number of times by spotting when the states start repeating.
+
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// Where the states start repeating: the state after `start + len` steps is the one after `start`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cycle {
+    pub start: usize,
+    pub len: usize,
+}
+
+/// The outcome of [`simulate`]
+#[derive(Debug, Clone)]
+pub struct Simulation<S> {
+    steps: usize,
+    history: Vec<S>,
+    cycle: Option<Cycle>,
+}
+
+impl<S> Simulation<S> {
+    /// Every distinct state in the order they were reached; `history()[i]` is the state after i
+    /// steps, starting from the initial state
+    pub fn history(&self) -> &[S] {
+        &self.history
+    }
+
+    /// The cycle, if one was found before running out of steps
+    pub fn cycle(&self) -> Option<Cycle> {
+        self.cycle
+    }
+
+    /// The state after exactly `step` steps, at any step if there is a cycle
+    pub fn state_at(&self, step: usize) -> &S {
+        match self.cycle {
+            Some(Cycle { start, len }) if step >= start => {
+                &self.history[start + (step - start) % len]
+            }
+            _ => &self.history[step],
+        }
+    }
+
+    /// The state after the `n` steps that were asked for
+    pub fn last(&self) -> &S {
+        self.state_at(self.steps)
+    }
+}
+
+/// Apply `step` to `state` `n` times, stopping as soon as a state repeats
+pub fn simulate<S, F>(state: S, mut step: F, n: usize) -> Simulation<S>
+where
+    S: Clone + Eq + Hash,
+    F: FnMut(&S) -> S,
+{
+    let mut seen = HashMap::from([(state.clone(), 0)]);
+    let mut history = vec![state];
+    let mut cycle = None;
+    for i in 1..=n {
+        let next = step(&history[i - 1]);
+        if let Some(&start) = seen.get(&next) {
+            cycle = Some(Cycle {
+                start,
+                len: i - start,
+            });
+            break;
+        }
+        seen.insert(next.clone(), i);
+        history.push(next);
+    }
+    Simulation {
+        steps: n,
+        history,
+        cycle,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn brute_force(mut state: u32, step: fn(&u32) -> u32, n: usize) -> u32 {
+        for _ in 0..n {
+            state = step(&state);
+        }
+        state
+    }
+
+    #[test]
+    fn test_cycle() {
+        let step = |x: &u32| (x * x + 1) % 101;
+        let sim = simulate(3, step, 1000);
+        assert_eq!(sim.cycle(), Some(Cycle { start: 12, len: 9 }));
+        assert_eq!(sim.history().len(), 21);
+        for n in 0..200 {
+            assert_eq!(
+                *simulate(3, step, n).last(),
+                brute_force(3, step, n),
+                "n = {n}"
+            );
+            assert_eq!(*sim.state_at(n), brute_force(3, step, n), "n = {n}");
+        }
+    }
+
+    #[test]
+    fn test_tail() {
+        // 0, 1, 2, 3, 4, 5, 3, 4, 5 ...
+        let step = |x: &u32| if *x == 5 { 3 } else { x + 1 };
+        let sim = simulate(0, step, 1_000_000_000);
+        assert_eq!(sim.cycle(), Some(Cycle { start: 3, len: 3 }));
+        assert_eq!(sim.history(), [0, 1, 2, 3, 4, 5]);
+        assert_eq!(*sim.last(), 3 + (1_000_000_000 - 3) % 3);
+
+        let short = simulate(0, step, 4);
+        assert_eq!(short.cycle(), None);
+        assert_eq!(short.history(), [0, 1, 2, 3, 4]);
+        assert_eq!(*short.last(), 4);
+    }
+}
@@ -0,0 +1,56 @@
+use std::fmt;
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{Answer, ParseError};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Part {
+    A,
+    B,
+}
+
+impl FromStr for Part {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "a" | "A" | "1" => Ok(Part::A),
+            "b" | "B" | "2" => Ok(Part::B),
+            _ => Err(format!("expected part a or b, got \"{s}\"")),
+        }
+    }
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Part::A => write!(f, "A"),
+            Part::B => write!(f, "B"),
+        }
+    }
+}
+
+/// One day's puzzle.
+///
+/// The input is parsed once and shared by both parts, so the runner can time
+/// parsing separately from solving.
+pub trait Solution {
+    type Input;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+
+    fn part_a(input: &Self::Input) -> Answer;
+
+    fn part_b(input: &Self::Input) -> Answer;
+
+    /// Parse the input and solve one part of it
+    fn solve(input: &str, part: Part) -> Result<Answer, ParseError> {
+        let parsed = Self::parse(input)?;
+        Ok(match part {
+            Part::A => Self::part_a(&parsed),
+            Part::B => Self::part_b(&parsed),
+        })
+    }
+}
@@ -0,0 +1,53 @@
+use std::fmt;
+
+use crate::Direction;
+
+/// A cell position, row 0 is the top row of the input
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Coord {
+    pub row: usize,
+    pub col: usize,
+}
+
+impl Coord {
+    pub const fn new(row: usize, col: usize) -> Self {
+        Self { row, col }
+    }
+
+    /// The position `drow` rows down and `dcol` columns right, or `None` above or left of the origin
+    pub fn offset(self, drow: isize, dcol: isize) -> Option<Self> {
+        Some(Self::new(
+            self.row.checked_add_signed(drow)?,
+            self.col.checked_add_signed(dcol)?,
+        ))
+    }
+
+    /// One step in `dir`, or `None` above or left of the origin.
+    /// The far edges are only known to [`Grid::step`](crate::Grid::step).
+    pub fn step(self, dir: impl Direction) -> Option<Self> {
+        let (drow, dcol) = dir.delta();
+        self.offset(drow, dcol)
+    }
+}
+
+impl fmt::Display for Coord {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {})", self.row, self.col)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Dir4, Dir8};
+
+    #[test]
+    fn test_step() {
+        let origin = Coord::new(0, 0);
+        assert_eq!(origin.step(Dir4::North), None);
+        assert_eq!(origin.step(Dir8::SouthWest), None);
+        assert_eq!(origin.step(Dir8::SouthEast), Some(Coord::new(1, 1)));
+        assert_eq!(Coord::new(2, 3).offset(-2, -3), Some(origin));
+        assert_eq!(Coord::new(2, 3).offset(-3, 0), None);
+    }
+}
@@ -0,0 +1,138 @@
+/// Something a [`Coord`](crate::Coord) can take one step in
+pub trait Direction: Copy {
+    /// The (row, column) change of one step; north is up the page
+    fn delta(self) -> (isize, isize);
+}
+
+/// The four directions along the grid lines
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Dir4 {
+    North,
+    East,
+    South,
+    West,
+}
+
+impl Dir4 {
+    /// Clockwise from north
+    pub const ALL: [Dir4; 4] = [Dir4::North, Dir4::East, Dir4::South, Dir4::West];
+
+    pub fn iter() -> impl Iterator<Item = Dir4> {
+        Self::ALL.into_iter()
+    }
+
+    /// The direction pointing the opposite way
+    pub fn invert(self) -> Self {
+        self.turn_right().turn_right()
+    }
+
+    /// A quarter turn clockwise
+    pub fn turn_right(self) -> Self {
+        Self::ALL[(self as usize + 1) % 4]
+    }
+
+    /// A quarter turn anticlockwise
+    pub fn turn_left(self) -> Self {
+        Self::ALL[(self as usize + 3) % 4]
+    }
+}
+
+impl Direction for Dir4 {
+    fn delta(self) -> (isize, isize) {
+        Dir8::from(self).delta()
+    }
+}
+
+/// The eight compass directions, diagonals included
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Dir8 {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+impl Dir8 {
+    /// Clockwise from north
+    pub const ALL: [Dir8; 8] = [
+        Dir8::North,
+        Dir8::NorthEast,
+        Dir8::East,
+        Dir8::SouthEast,
+        Dir8::South,
+        Dir8::SouthWest,
+        Dir8::West,
+        Dir8::NorthWest,
+    ];
+
+    pub fn iter() -> impl Iterator<Item = Dir8> {
+        Self::ALL.into_iter()
+    }
+
+    /// The direction pointing the opposite way
+    pub fn invert(self) -> Self {
+        Self::ALL[(self as usize + 4) % 8]
+    }
+
+    /// An eighth of a turn clockwise
+    pub fn turn_right(self) -> Self {
+        Self::ALL[(self as usize + 1) % 8]
+    }
+
+    /// An eighth of a turn anticlockwise
+    pub fn turn_left(self) -> Self {
+        Self::ALL[(self as usize + 7) % 8]
+    }
+}
+
+impl Direction for Dir8 {
+    fn delta(self) -> (isize, isize) {
+        match self {
+            Dir8::North => (-1, 0),
+            Dir8::NorthEast => (-1, 1),
+            Dir8::East => (0, 1),
+            Dir8::SouthEast => (1, 1),
+            Dir8::South => (1, 0),
+            Dir8::SouthWest => (1, -1),
+            Dir8::West => (0, -1),
+            Dir8::NorthWest => (-1, -1),
+        }
+    }
+}
+
+impl From<Dir4> for Dir8 {
+    fn from(dir: Dir4) -> Self {
+        match dir {
+            Dir4::North => Dir8::North,
+            Dir4::East => Dir8::East,
+            Dir4::South => Dir8::South,
+            Dir4::West => Dir8::West,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_turns() {
+        assert_eq!(Dir4::North.turn_right(), Dir4::East);
+        assert_eq!(Dir4::North.turn_left(), Dir4::West);
+        assert_eq!(Dir8::North.turn_left(), Dir8::NorthWest);
+        for dir in Dir4::iter() {
+            assert_eq!(dir.turn_left().turn_right(), dir);
+            assert_eq!(dir.invert().invert(), dir);
+            assert_eq!(Dir8::from(dir.invert()), Dir8::from(dir).invert());
+        }
+        for dir in Dir8::iter() {
+            let (dr, dc) = dir.delta();
+            assert_eq!(dir.invert().delta(), (-dr, -dc));
+            assert_eq!(dir.turn_right().turn_left(), dir);
+        }
+    }
+}
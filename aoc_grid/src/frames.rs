@@ -0,0 +1,121 @@
+//! Snapshots of a grid as a solver works on it, for the runner to animate.
+//!
+//! Solvers call [`emit`] wherever a step is worth seeing. Nothing is built unless
+//! the runner is collecting frames with [`with_sink`], so the calls can stay in.
+
+use std::cell::RefCell;
+use std::fmt::Display;
+
+use crate::{Coord, Grid};
+
+/// One picture: every cell as a char, the cells to draw attention to, and a caption
+#[derive(Debug, Clone, PartialEq)]
+pub struct Frame {
+    pub cells: Grid<char>,
+    pub highlights: Vec<Coord>,
+    /// A second set of cells, told apart from the highlights
+    pub marks: Vec<Coord>,
+    pub lines: Vec<Line>,
+    pub caption: String,
+}
+
+/// A line along the grid, between cells
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Line {
+    /// Along the top of this row
+    Row(usize),
+    /// Along the left of this column
+    Column(usize),
+}
+
+impl Frame {
+    /// Each cell drawn as the first char of its `Display`
+    pub fn new<T: Display>(grid: &Grid<T>) -> Self {
+        Self {
+            cells: grid.map(|cell| cell.to_string().chars().next().unwrap_or(' ')),
+            highlights: Vec::new(),
+            marks: Vec::new(),
+            lines: Vec::new(),
+            caption: String::new(),
+        }
+    }
+
+    pub fn highlight(mut self, coords: impl IntoIterator<Item = Coord>) -> Self {
+        self.highlights.extend(coords);
+        self
+    }
+
+    pub fn mark(mut self, coords: impl IntoIterator<Item = Coord>) -> Self {
+        self.marks.extend(coords);
+        self
+    }
+
+    pub fn line(mut self, line: Line) -> Self {
+        self.lines.push(line);
+        self
+    }
+
+    pub fn caption(mut self, caption: impl Into<String>) -> Self {
+        self.caption = caption.into();
+        self
+    }
+}
+
+type Sink = Box<dyn FnMut(Frame)>;
+
+thread_local! {
+    static SINK: RefCell<Option<Sink>> = const { RefCell::new(None) };
+}
+
+/// Hand a frame to the sink, if there is one; `frame` is only called then
+pub fn emit(frame: impl FnOnce() -> Frame) {
+    SINK.with(|sink| {
+        if let Some(sink) = sink.borrow_mut().as_mut() {
+            sink(frame());
+        }
+    });
+}
+
+/// Run `f` with every frame it emits on this thread going to `sink`
+pub fn with_sink<R>(sink: impl FnMut(Frame) + 'static, f: impl FnOnce() -> R) -> R {
+    struct Restore(Option<Sink>);
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            SINK.with(|sink| *sink.borrow_mut() = self.0.take());
+        }
+    }
+    let _restore = Restore(SINK.with(|slot| slot.borrow_mut().replace(Box::new(sink))));
+    f()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::rc::Rc;
+
+    use super::*;
+
+    #[test]
+    fn test_sink() {
+        let grid = Grid::from_vec(2, 1, vec![1, 23]);
+        emit(|| panic!("built a frame with no sink"));
+
+        let frames = Rc::new(RefCell::new(Vec::new()));
+        let collected = frames.clone();
+        let answer = with_sink(
+            move |frame| collected.borrow_mut().push(frame),
+            || {
+                emit(|| Frame::new(&grid).caption("first"));
+                emit(|| Frame::new(&grid).highlight([Coord::new(0, 1)]));
+                42
+            },
+        );
+        assert_eq!(answer, 42);
+        emit(|| panic!("the sink outlived with_sink"));
+
+        let frames = frames.borrow();
+        assert_eq!(frames.len(), 2);
+        assert_eq!(frames[0].cells.to_string(), "12\n");
+        assert_eq!(frames[0].caption, "first");
+        assert_eq!(frames[1].highlights, [Coord::new(0, 1)]);
+    }
+}
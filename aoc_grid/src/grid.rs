@@ -0,0 +1,372 @@
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+use aoc_common::ParseError;
+
+use crate::{Coord, Dir4, Dir8, Direction};
+
+/// A rectangular grid stored row by row in one flat buffer
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// A grid from its cells in row order
+    pub fn from_vec(width: usize, height: usize, cells: Vec<T>) -> Self {
+        assert_eq!(
+            cells.len(),
+            width * height,
+            "{width}x{height} grid needs {} cells",
+            width * height
+        );
+        Self {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// Parse a picture with one cell per char. `cell` maps each char, and
+    /// returning None rejects it as not being `expected`. Every row must be
+    /// as wide as the first.
+    pub fn parse(
+        input: &str,
+        cell: impl FnMut(char) -> Option<T>,
+        expected: &str,
+    ) -> Result<Self, ParseError> {
+        Self::parse_lines(input, input.lines(), cell, expected)
+    }
+
+    /// Like [`Grid::parse`], for a picture that is only some of the lines of
+    /// `input`. The lines should be slices of `input`, so that errors point
+    /// at the right place in it.
+    pub fn parse_lines<'a>(
+        input: &str,
+        lines: impl IntoIterator<Item = &'a str>,
+        mut cell: impl FnMut(char) -> Option<T>,
+        expected: &str,
+    ) -> Result<Self, ParseError> {
+        let mut cells = Vec::new();
+        let mut width = None;
+        let mut height = 0;
+        for line in lines {
+            let line = line.trim_end();
+            let mut row_width = 0;
+            for (i, c) in line.char_indices() {
+                let value = cell(c)
+                    .ok_or_else(|| ParseError::new(input, &line[i..i + c.len_utf8()], expected))?;
+                cells.push(value);
+                row_width += 1;
+            }
+            match width {
+                None => width = Some(row_width),
+                Some(width) if width != row_width => {
+                    return Err(ParseError::new(
+                        input,
+                        line,
+                        format!("a row {width} cells wide"),
+                    ));
+                }
+                Some(_) => {}
+            }
+            height += 1;
+        }
+        Ok(Self::from_vec(width.unwrap_or(0), height, cells))
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn in_bounds(&self, coord: Coord) -> bool {
+        coord.row < self.height && coord.col < self.width
+    }
+
+    fn offset(&self, coord: Coord) -> Option<usize> {
+        self.in_bounds(coord)
+            .then_some(coord.row * self.width + coord.col)
+    }
+
+    pub fn get(&self, coord: Coord) -> Option<&T> {
+        self.offset(coord).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, coord: Coord) -> Option<&mut T> {
+        self.offset(coord).map(|i| &mut self.cells[i])
+    }
+
+    pub fn swap(&mut self, a: Coord, b: Coord) {
+        let a = self.offset(a).expect("coord out of bounds");
+        let b = self.offset(b).expect("coord out of bounds");
+        self.cells.swap(a, b);
+    }
+
+    /// Every position, row by row
+    pub fn coords(&self) -> impl Iterator<Item = Coord> {
+        let width = self.width;
+        (0..self.height).flat_map(move |row| (0..width).map(move |col| Coord::new(row, col)))
+    }
+
+    /// Every cell with its position, row by row
+    pub fn iter(&self) -> impl Iterator<Item = (Coord, &T)> {
+        self.coords().zip(&self.cells)
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        assert!(row < self.height, "row {row} out of bounds");
+        &self.cells[row * self.width..(row + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // chunks() would panic on a 0-wide grid
+        (0..self.height).map(|row| self.row(row))
+    }
+
+    pub fn column(&self, col: usize) -> impl Iterator<Item = &T> {
+        assert!(col < self.width, "column {col} out of bounds");
+        self.cells.iter().skip(col).step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|col| self.column(col))
+    }
+
+    /// One step in `dir`, or `None` off any edge
+    pub fn step(&self, coord: Coord, dir: impl Direction) -> Option<Coord> {
+        coord.step(dir).filter(|&next| self.in_bounds(next))
+    }
+
+    /// One step in `dir` on a torus: leaving one edge comes back in at the opposite one
+    pub fn step_wrapping(&self, coord: Coord, dir: impl Direction) -> Coord {
+        assert!(self.in_bounds(coord), "{coord} out of bounds");
+        let (drow, dcol) = dir.delta();
+        let wrap = |at: usize, delta: isize, len: usize| {
+            (at as isize + delta).rem_euclid(len as isize) as usize
+        };
+        Coord::new(
+            wrap(coord.row, drow, self.height),
+            wrap(coord.col, dcol, self.width),
+        )
+    }
+
+    /// The in-bounds neighbours above, right of, below and left of `coord`
+    pub fn neighbours4(&self, coord: Coord) -> impl Iterator<Item = Coord> + '_ {
+        Dir4::iter().filter_map(move |dir| self.step(coord, dir))
+    }
+
+    /// The in-bounds neighbours of `coord`, diagonals included, clockwise from above
+    pub fn neighbours8(&self, coord: Coord) -> impl Iterator<Item = Coord> + '_ {
+        Dir8::iter().filter_map(move |dir| self.step(coord, dir))
+    }
+
+    /// A grid of the same shape with every cell mapped through `f`
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid::from_vec(self.width, self.height, self.cells.iter().map(f).collect())
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self {
+        Self::from_vec(width, height, vec![fill; width * height])
+    }
+
+    /// A grid `width` x `height` whose cell at each position is `cell(coord)`
+    /// of this grid
+    fn remap(&self, width: usize, height: usize, cell: impl Fn(Coord) -> Coord) -> Self {
+        let mut cells = Vec::with_capacity(width * height);
+        for row in 0..height {
+            for col in 0..width {
+                cells.push(self[cell(Coord::new(row, col))].clone());
+            }
+        }
+        Self::from_vec(width, height, cells)
+    }
+
+    /// Rows become columns
+    pub fn transpose(&self) -> Self {
+        self.remap(self.height, self.width, |c| Coord::new(c.col, c.row))
+    }
+
+    /// Rotate a quarter turn clockwise
+    pub fn rotate_cw(&self) -> Self {
+        let height = self.height;
+        self.remap(self.height, self.width, |c| {
+            Coord::new(height - 1 - c.col, c.row)
+        })
+    }
+
+    /// Rotate a quarter turn counterclockwise
+    pub fn rotate_ccw(&self) -> Self {
+        let width = self.width;
+        self.remap(self.height, self.width, |c| {
+            Coord::new(c.col, width - 1 - c.row)
+        })
+    }
+
+    /// Mirror left to right
+    pub fn flip_horizontal(&self) -> Self {
+        let width = self.width;
+        self.remap(self.width, self.height, |c| {
+            Coord::new(c.row, width - 1 - c.col)
+        })
+    }
+
+    /// Mirror top to bottom
+    pub fn flip_vertical(&self) -> Self {
+        let height = self.height;
+        self.remap(self.width, self.height, |c| {
+            Coord::new(height - 1 - c.row, c.col)
+        })
+    }
+}
+
+impl<T> Index<Coord> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, coord: Coord) -> &T {
+        self.get(coord)
+            .unwrap_or_else(|| panic!("{coord} out of bounds"))
+    }
+}
+
+impl<T> IndexMut<Coord> for Grid<T> {
+    fn index_mut(&mut self, coord: Coord) -> &mut T {
+        self.get_mut(coord)
+            .unwrap_or_else(|| panic!("{coord} out of bounds"))
+    }
+}
+
+/// Prints one line per row, the way the grid looks in the puzzle
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid(picture: &str) -> Grid<char> {
+        Grid::parse(picture, Some, "anything").unwrap()
+    }
+
+    #[test]
+    fn test_parse() {
+        let g = Grid::parse(
+            "#.\n.#\n..\n",
+            |c| match c {
+                '#' => Some(true),
+                '.' => Some(false),
+                _ => None,
+            },
+            "# or .",
+        )
+        .unwrap();
+        assert_eq!((g.width(), g.height()), (2, 3));
+        assert_eq!(g.get(Coord::new(1, 1)), Some(&true));
+        assert_eq!(g.get(Coord::new(2, 0)), Some(&false));
+        assert_eq!(g.get(Coord::new(3, 0)), None);
+        assert_eq!(g.get(Coord::new(0, 2)), None);
+
+        let err = Grid::parse("#.\n.x\n", |c| (c != 'x').then_some(c), "# or .").unwrap_err();
+        assert_eq!((err.line, err.column, err.token.as_str()), (2, 2, "x"));
+        let err = Grid::parse("#.\n.\n", Some, "# or .").unwrap_err();
+        assert_eq!((err.line, err.expected.as_str()), (2, "a row 2 cells wide"));
+
+        let input = "..\n\n#.\n#x\n";
+        let lines = input.lines().skip(2);
+        let err =
+            Grid::parse_lines(input, lines, |c| (c != 'x').then_some(c), "# or .").unwrap_err();
+        assert_eq!((err.line, err.column), (4, 2));
+
+        let empty = grid("");
+        assert_eq!((empty.width(), empty.height()), (0, 0));
+        assert_eq!(empty.rows().count(), 0);
+    }
+
+    #[test]
+    fn test_get_mut_and_swap() {
+        let mut g = grid("ab\ncd\n");
+        *g.get_mut(Coord::new(0, 1)).unwrap() = 'x';
+        g[Coord::new(1, 0)] = 'y';
+        assert!(g.get_mut(Coord::new(2, 0)).is_none());
+        g.swap(Coord::new(0, 0), Coord::new(1, 1));
+        assert_eq!(g.to_string(), "dx\nya\n");
+    }
+
+    #[test]
+    fn test_views() {
+        let g = grid("abc\ndef\n");
+        assert_eq!(g.row(1), ['d', 'e', 'f']);
+        assert_eq!(g.column(2).collect::<String>(), "cf");
+        assert_eq!(
+            g.columns()
+                .map(|col| col.collect::<String>())
+                .collect::<Vec<_>>(),
+            ["ad", "be", "cf"]
+        );
+        assert_eq!(g.rows().count(), 2);
+        assert_eq!(
+            g.iter().find(|(_, &c)| c == 'e').map(|(coord, _)| coord),
+            Some(Coord::new(1, 1))
+        );
+        assert_eq!(g.map(|c| c.to_ascii_uppercase()).to_string(), "ABC\nDEF\n");
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let g = grid("abc\ndef\nghi\n");
+        let at = |coords: Vec<Coord>| coords.into_iter().map(|c| g[c]).collect::<String>();
+        assert_eq!(at(g.neighbours4(Coord::new(1, 1)).collect()), "bfhd");
+        assert_eq!(at(g.neighbours8(Coord::new(1, 1)).collect()), "bcfihgda");
+        assert_eq!(at(g.neighbours4(Coord::new(0, 0)).collect()), "bd");
+        assert_eq!(at(g.neighbours8(Coord::new(2, 2)).collect()), "fhe");
+    }
+
+    #[test]
+    fn test_step() {
+        let g = grid("abc\ndef\n");
+        let corner = Coord::new(1, 2);
+        assert_eq!(g.step(corner, Dir4::North), Some(Coord::new(0, 2)));
+        assert_eq!(g.step(corner, Dir4::East), None);
+        assert_eq!(g.step(corner, Dir8::SouthWest), None);
+        assert_eq!(g.step(Coord::new(0, 0), Dir4::West), None);
+
+        assert_eq!(g.step_wrapping(corner, Dir4::East), Coord::new(1, 0));
+        assert_eq!(g.step_wrapping(corner, Dir8::SouthEast), Coord::new(0, 0));
+        assert_eq!(
+            g.step_wrapping(Coord::new(0, 0), Dir8::NorthWest),
+            Coord::new(1, 2)
+        );
+    }
+
+    #[test]
+    fn test_transforms() {
+        let g = grid("abc\ndef\n");
+        assert_eq!(g.transpose().to_string(), "ad\nbe\ncf\n");
+        assert_eq!(g.rotate_cw().to_string(), "da\neb\nfc\n");
+        assert_eq!(g.rotate_ccw().to_string(), "cf\nbe\nad\n");
+        assert_eq!(g.flip_horizontal().to_string(), "cba\nfed\n");
+        assert_eq!(g.flip_vertical().to_string(), "def\nabc\n");
+        assert_eq!(g.rotate_cw().rotate_ccw(), g);
+        assert_eq!(
+            g.rotate_cw().rotate_cw(),
+            g.flip_horizontal().flip_vertical()
+        );
+        assert_eq!(g.transpose().transpose(), g);
+    }
+}
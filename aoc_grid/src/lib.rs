@@ -0,0 +1,10 @@
+//! A 2D grid of cells, shared by the puzzles that come as a picture.
+
+mod coord;
+mod dir;
+pub mod frames;
+mod grid;
+
+pub use coord::Coord;
+pub use dir::{Dir4, Dir8, Direction};
+pub use grid::Grid;
@@ -0,0 +1,3 @@
+fn main() {
+    aoc_common::examples::write_tests();
+}
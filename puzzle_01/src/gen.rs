@@ -0,0 +1,51 @@
+//! Random calibration documents: `size` lines of letters, digits and spelled-out digits,
+//! every line with at least one real digit so that part A can read it too.
+//!
+//! The letters never include z, so no line can spell out a zero.
+
+use std::fmt::Write;
+
+use aoc_common::gen::{IndexedRandom, Rng, RngExt};
+
+use crate::WORDS;
+
+pub fn generate<R: Rng>(rng: &mut R, size: usize) -> String {
+    let mut input = String::new();
+    for _ in 0..size {
+        let mut pieces = (0..rng.random_range(0..8))
+            .map(|_| match rng.random_range(0..4) {
+                0 => rng.random_range(1..=9).to_string(),
+                1 => WORDS.choose(rng).unwrap().to_string(),
+                _ => (0..rng.random_range(1..=4))
+                    .map(|_| rng.random_range('a'..='y'))
+                    .collect(),
+            })
+            .collect::<Vec<String>>();
+        let at = rng.random_range(0..=pieces.len());
+        pieces.insert(at, rng.random_range(1..=9).to_string());
+        let _ = writeln!(input, "{}", pieces.concat());
+    }
+    input
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::gen::seeded;
+    use aoc_common::Solution;
+
+    use super::*;
+    use crate::Puzzle;
+
+    #[test]
+    fn test_generate() {
+        for seed in 0..20 {
+            let input = generate(&mut seeded(seed), 20);
+            assert!(!input.contains('z'));
+            let lines = Puzzle::parse(&input).unwrap();
+            assert_eq!(lines.len(), 20);
+            assert!(lines.iter().all(|line| line.digits.is_some()));
+            Puzzle::part_a(&lines);
+            Puzzle::part_b(&lines);
+        }
+    }
+}
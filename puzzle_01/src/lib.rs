@@ -0,0 +1,185 @@
+pub mod gen;
+
+use aho_corasick::AhoCorasick;
+use aoc_common::{Answer, ParseError, Solution};
+
+const WORDS: [&str; 9] = [
+    "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+
+/// Finds the digits in a line in a single pass, and the spelled-out ones too if asked.
+/// Matches may overlap, so "twone" holds a 2 and a 1.
+struct DigitFinder {
+    automaton: AhoCorasick,
+    /// The digit each pattern stands for, by pattern index
+    values: Vec<u32>,
+}
+
+impl DigitFinder {
+    fn new(spelled_out: bool) -> Self {
+        let mut patterns = (1..=9).map(|digit| digit.to_string()).collect::<Vec<_>>();
+        let mut values = (1..=9).collect::<Vec<_>>();
+        if spelled_out {
+            patterns.extend(WORDS.map(String::from));
+            values.extend(1..=9);
+        }
+        let automaton = AhoCorasick::new(&patterns).expect("the digit patterns are valid");
+        Self { automaton, values }
+    }
+
+    /// The first and last digit in `line`
+    fn ends(&self, line: &str) -> Option<(u32, u32)> {
+        // overlapping matches come out by where they end, and as no pattern holds
+        // another, that is also the order they start in
+        let mut digits = self
+            .automaton
+            .find_overlapping_iter(line)
+            .map(|found| self.values[found.pattern().as_usize()]);
+        let first = digits.next()?;
+        Some((first, digits.last().unwrap_or(first)))
+    }
+}
+
+/// The first and last digit of one line of the calibration document, as each part reads them
+pub struct Line {
+    /// Part A only counts digits, and a line may have none of those
+    digits: Option<(u32, u32)>,
+    spelled_out: (u32, u32),
+}
+
+fn calibration_value((first, last): (u32, u32)) -> u32 {
+    first * 10 + last
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input = Vec<Line>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let (digits, spelled_out) = (DigitFinder::new(false), DigitFinder::new(true));
+        input
+            .lines()
+            .map(|line| {
+                Ok(Line {
+                    digits: digits.ends(line),
+                    spelled_out: spelled_out.ends(line).ok_or_else(|| {
+                        ParseError::new(input, line, "a digit, or one spelled out")
+                    })?,
+                })
+            })
+            .collect()
+    }
+
+    fn part_a(lines: &Self::Input) -> Answer {
+        lines
+            .iter()
+            .map(|line| calibration_value(line.digits.expect("a line without digits")))
+            .sum::<u32>()
+            .into()
+    }
+
+    fn part_b(lines: &Self::Input) -> Answer {
+        lines
+            .iter()
+            .map(|line| calibration_value(line.spelled_out))
+            .sum::<u32>()
+            .into()
+    }
+}
+
+aoc_common::example_tests!();
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+    use std::process::Command;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::{env, fs, process};
+
+    use aoc_common::differential::{check, Differential};
+    use aoc_common::known::PartAnswers;
+    use aoc_common::Part;
+    use proptest::prelude::*;
+
+    use super::*;
+
+    #[test]
+    fn test_overlaps() {
+        let finder = DigitFinder::new(true);
+        assert_eq!(finder.ends("twone"), Some((2, 1)));
+        assert_eq!(finder.ends("eighthree"), Some((8, 3)));
+        assert_eq!(finder.ends("sevenine2oneight"), Some((7, 8)));
+        assert_eq!(finder.ends("xthreex"), Some((3, 3)));
+        assert_eq!(finder.ends("zero"), None);
+        assert_eq!(DigitFinder::new(false).ends("one2three"), Some((2, 2)));
+    }
+
+    /// What solve.py, the day's first solution, makes of `input`. It only does part B, and
+    /// reads a file called input from where it runs, so each call gets a directory of its own.
+    fn solve_py(input: &str) -> Option<Answer> {
+        static CALLS: AtomicUsize = AtomicUsize::new(0);
+        let call = CALLS.fetch_add(1, Ordering::Relaxed);
+        let dir = env::temp_dir().join(format!("puzzle_01-solve-py-{}-{call}", process::id()));
+        fs::create_dir_all(&dir).ok()?;
+        fs::write(dir.join("input"), input).ok()?;
+        let output = Command::new("python3")
+            .arg(Path::new(env!("CARGO_MANIFEST_DIR")).join("solve.py"))
+            .current_dir(&dir)
+            .output();
+        let _ = fs::remove_dir_all(&dir);
+        // the total comes last, after the pattern it printed
+        let total = String::from_utf8(output.ok()?.stdout).ok()?;
+        total
+            .lines()
+            .last()?
+            .trim()
+            .parse::<u64>()
+            .ok()
+            .map(Answer::from)
+    }
+
+    fn input(lines: &[String]) -> String {
+        lines.iter().map(|line| format!("{line}\n")).collect()
+    }
+
+    #[test]
+    fn test_solve_py_agrees() {
+        if solve_py("1\n").is_none() {
+            eprintln!("skipping: could not run solve.py with python3");
+            return;
+        }
+        // filler leaves out z, as solve.py also counts "zero" and the puzzle doesn't
+        let piece = prop_oneof![
+            "[a-y]{1,3}",
+            "[1-9]",
+            proptest::sample::select(WORDS.to_vec()).prop_map(String::from),
+        ];
+        let line = (
+            prop::collection::vec(piece.clone(), 0..6),
+            prop_oneof![
+                "[1-9]",
+                proptest::sample::select(WORDS.to_vec()).prop_map(String::from)
+            ],
+            prop::collection::vec(piece, 0..6),
+        )
+            .prop_map(|(before, digit, after)| before.concat() + &digit + &after.concat());
+        let lines = prop::collection::vec(line, 1..5);
+
+        check(
+            Path::new(env!("CARGO_MANIFEST_DIR")),
+            lines,
+            Differential {
+                input: |lines: &Vec<String>| input(lines),
+                trusted: |lines: &Vec<String>| PartAnswers {
+                    a: None,
+                    b: solve_py(&input(lines)),
+                },
+                tested: |lines: &Vec<String>| PartAnswers {
+                    a: None,
+                    b: Puzzle::solve(&input(lines), Part::B).ok(),
+                },
+            },
+        );
+    }
+}
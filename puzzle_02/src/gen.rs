@@ -0,0 +1,45 @@
+//! Random games: `size` of them, each with up to six draws of up to 20 cubes a color.
+
+use std::fmt::Write;
+
+use aoc_common::gen::{Rng, RngExt, SliceRandom};
+
+pub fn generate<R: Rng>(rng: &mut R, size: usize) -> String {
+    let mut input = String::new();
+    for game in 1..=size {
+        let mut draws = Vec::new();
+        for _ in 0..rng.random_range(1..=6) {
+            let mut colors = ["red", "green", "blue"];
+            colors.shuffle(rng);
+            let shown = rng.random_range(1..=colors.len());
+            let cubes = colors[..shown]
+                .iter()
+                .map(|color| format!("{} {color}", rng.random_range(1..=20)))
+                .collect::<Vec<_>>();
+            draws.push(cubes.join(", "));
+        }
+        let _ = writeln!(input, "Game {game}: {}", draws.join("; "));
+    }
+    input
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::gen::seeded;
+    use aoc_common::Solution;
+
+    use super::*;
+    use crate::Puzzle;
+
+    #[test]
+    fn test_generate() {
+        for seed in 0..20 {
+            let input = generate(&mut seeded(seed), 10);
+            let games = Puzzle::parse(&input).unwrap();
+            assert_eq!(games.len(), 10);
+            assert!(games.iter().all(|game| !game.cubes.is_empty()));
+            Puzzle::part_a(&games);
+            Puzzle::part_b(&games);
+        }
+    }
+}
@@ -0,0 +1,105 @@
+pub mod gen;
+
+use std::cmp::max;
+use std::str::FromStr;
+
+use aoc_common::parse::{number, numbered};
+use aoc_common::{Answer, ParseError, Solution};
+use strum::EnumString;
+
+#[derive(Debug, PartialEq, EnumString)]
+enum Color {
+    #[strum(ascii_case_insensitive)]
+    Red,
+    #[strum(ascii_case_insensitive)]
+    Green,
+    #[strum(ascii_case_insensitive)]
+    Blue,
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input = Vec<Game>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input.lines().map(|line| parse_line(input, line)).collect()
+    }
+
+    fn part_a(games: &Self::Input) -> Answer {
+        let bag = RgbCount(12, 13, 14);
+        let mut total = 0;
+        for game in games {
+            if game.cubes.iter().all(|set| bag.can_contain(set)) {
+                total += game.idx;
+            }
+        }
+        total.into()
+    }
+
+    fn part_b(games: &Self::Input) -> Answer {
+        let mut total_power = 0;
+        for game in games {
+            total_power += find_minimum_cubes(game).power();
+        }
+        total_power.into()
+    }
+}
+
+#[derive(Copy, Clone)]
+struct RgbCount(i32, i32, i32);
+
+impl RgbCount {
+    fn can_contain(&self, other: &RgbCount) -> bool {
+        other.0 <= self.0 && other.1 <= self.1 && other.2 <= self.2
+    }
+
+    fn power(&self) -> i32 {
+        self.0 * self.1 * self.2
+    }
+}
+
+pub struct Game {
+    idx: i32,
+    cubes: Vec<RgbCount>,
+}
+
+fn parse_line(input: &str, line: &str) -> Result<Game, ParseError> {
+    let (idx, draws) = numbered(input, line, "Game")?;
+    let mut cubes: Vec<RgbCount> = Vec::new();
+    let draws = draws.split(';');
+    for draw in draws {
+        let mut counts = RgbCount(0, 0, 0);
+        for c in draw.split(',') {
+            let mut sw = c.split_whitespace();
+            let number_str = sw
+                .next()
+                .ok_or_else(|| ParseError::new(input, c, "a cube count"))?;
+            let number: i32 = number(input, number_str)?;
+            let color_str = sw
+                .next()
+                .ok_or_else(|| ParseError::missing(input, number_str, "a color"))?;
+            let color = Color::from_str(color_str)
+                .map_err(|_| ParseError::new(input, color_str, "red, green or blue"))?;
+            match color {
+                Color::Red => counts.0 += number,
+                Color::Green => counts.1 += number,
+                Color::Blue => counts.2 += number,
+            }
+            cubes.push(counts);
+        }
+    }
+    Ok(Game { idx, cubes })
+}
+
+fn find_minimum_cubes(game: &Game) -> RgbCount {
+    let mut minset = RgbCount(0, 0, 0);
+    for draw in &game.cubes {
+        minset.0 = max(minset.0, draw.0);
+        minset.1 = max(minset.1, draw.1);
+        minset.2 = max(minset.2, draw.2);
+    }
+    minset
+}
+
+aoc_common::example_tests!();
@@ -0,0 +1,60 @@
+//! Random engine schematics: a `size` by `size` grid of part numbers and symbols, about a
+//! third of the symbols gears.
+
+use aoc_common::gen::{IndexedRandom, Rng, RngExt};
+
+const SYMBOLS: &[char] = &['*', '#', '+', '$', '/', '@', '=', '%', '&', '-'];
+
+pub fn generate<R: Rng>(rng: &mut R, size: usize) -> String {
+    let mut input = String::new();
+    for _ in 0..size {
+        let mut row = vec!['.'; size];
+        let mut col = 0;
+        while col < size {
+            let roll = rng.random_range(0.0..1.0);
+            if roll < 0.15 {
+                let digits = rng.random_range(1..=3).min(size - col);
+                let value =
+                    rng.random_range(10u32.pow(digits as u32 - 1)..10u32.pow(digits as u32));
+                for (i, digit) in value.to_string().chars().enumerate() {
+                    row[col + i] = digit;
+                }
+                // keep the next number from running into this one
+                col += digits + 1;
+            } else {
+                if roll < 0.22 {
+                    row[col] = match rng.random_bool(0.3) {
+                        true => '*',
+                        false => *SYMBOLS.choose(rng).unwrap(),
+                    };
+                }
+                col += 1;
+            }
+        }
+        input.extend(row);
+        input.push('\n');
+    }
+    input
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::gen::seeded;
+    use aoc_common::Solution;
+
+    use super::*;
+    use crate::Puzzle;
+
+    #[test]
+    fn test_generate() {
+        for seed in 0..20 {
+            let input = generate(&mut seeded(seed), 30);
+            let schematic = Puzzle::parse(&input).unwrap();
+            assert_eq!(schematic.symbol_map.0.width(), 30);
+            assert_eq!(schematic.symbol_map.0.height(), 30);
+            assert!(schematic.numbers.iter().all(|number| number.value > 0));
+            Puzzle::part_a(&schematic);
+            Puzzle::part_b(&schematic);
+        }
+    }
+}
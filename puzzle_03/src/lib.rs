@@ -0,0 +1,207 @@
+pub mod gen;
+
+use anyhow::{anyhow, Result};
+use aoc_common::parse::number;
+use aoc_common::{Answer, ParseError, Solution};
+use aoc_grid::{Coord, Grid};
+use regex::Regex;
+use std::collections::HashSet;
+use std::hash::Hash;
+
+pub struct Schematic {
+    symbol_map: SymbolMap,
+    numbers: Vec<Number>,
+    stars: Vec<Coord>,
+}
+
+fn parse_schematic(input: &str) -> Result<Schematic, ParseError> {
+    let number_pattern = Regex::new(r"([0-9]+)").unwrap();
+
+    let chars = Grid::parse(input, Some, "a character")?;
+    let symbol_map = SymbolMap(chars.map(|c| !(c.is_numeric() || *c == '.')));
+    let stars = chars
+        .iter()
+        .filter(|(_, c)| **c == '*')
+        .map(|(pos, _)| pos)
+        .collect();
+
+    let mut numbers: Vec<Number> = vec![];
+    for (row_idx, line) in input.lines().enumerate() {
+        let line = line.trim_end();
+        for m in number_pattern.find_iter(line) {
+            // the grid has a column per char, and the symbols before may take several bytes
+            let start_idx = line[..m.start()].chars().count();
+            numbers.push(Number {
+                value: number(input, m.as_str())?,
+                position: Region {
+                    line: row_idx,
+                    start_idx,
+                    end_idx: start_idx + m.as_str().len(),
+                },
+            })
+        }
+    }
+
+    Ok(Schematic {
+        symbol_map,
+        numbers,
+        stars,
+    })
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input = Schematic;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_schematic(input)
+    }
+
+    fn part_a(schematic: &Self::Input) -> Answer {
+        let mut total = 0;
+        for num in &schematic.numbers {
+            if schematic
+                .symbol_map
+                .is_region_adjacent_to_symbol(&num.position)
+            {
+                total += num.value;
+            }
+        }
+        total.into()
+    }
+
+    fn part_b(schematic: &Self::Input) -> Answer {
+        let symbols = &schematic.symbol_map.0;
+        let mut number_map = NumberMap::new(symbols.width(), symbols.height());
+        for num in &schematic.numbers {
+            number_map.add_number(num).unwrap();
+        }
+
+        let mut total_ratios = 0;
+        for starloc in &schematic.stars {
+            let numbers = number_map.get_adjacent_numbers(*starloc);
+            if numbers.len() == 2 {
+                total_ratios += numbers.iter().map(|n| n.value).product::<i32>();
+            }
+        }
+        total_ratios.into()
+    }
+}
+
+#[derive(Eq, PartialEq, Clone, Copy, Debug)]
+struct Region {
+    line: usize,
+    start_idx: usize,
+    end_idx: usize,
+}
+
+#[derive(Eq, PartialEq, Clone, Copy, Debug)]
+struct Number {
+    value: i32,
+    position: Region,
+}
+
+impl std::fmt::Display for Number {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} [{} {}-{}]",
+            self.value, self.position.line, self.position.start_idx, self.position.end_idx
+        )
+    }
+}
+
+impl Hash for Number {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.value.hash(state);
+        self.position.line.hash(state);
+        self.position.start_idx.hash(state);
+        self.position.end_idx.hash(state);
+    }
+}
+
+struct SymbolMap(Grid<bool>);
+
+impl SymbolMap {
+    fn is_region_adjacent_to_symbol(&self, region: &Region) -> bool {
+        // scan around each character for a symbol
+        (region.start_idx..region.end_idx).any(|col| {
+            self.0
+                .neighbours8(Coord::new(region.line, col))
+                .any(|pos| self.0[pos])
+        })
+    }
+
+    /// Debug dump of the map, as saved in symbol_map.out
+    #[allow(dead_code)]
+    fn as_str(&self) -> String {
+        self.0
+            .map(|b| match *b {
+                true => 't',
+                false => '.',
+            })
+            .to_string()
+    }
+}
+
+struct NumberMap<'a>(Grid<Option<&'a Number>>);
+
+impl<'a> NumberMap<'a> {
+    fn new(cols: usize, rows: usize) -> Self {
+        Self(Grid::new(cols, rows, None))
+    }
+
+    fn add_number(&mut self, num: &'a Number) -> Result<()> {
+        for col in num.position.start_idx..num.position.end_idx {
+            let cell = self
+                .0
+                .get_mut(Coord::new(num.position.line, col))
+                .ok_or_else(|| anyhow!("out of bounds"))?;
+            if cell.is_some() {
+                return Err(anyhow!(
+                    "trying to overwrite a filled region in the number map"
+                ));
+            }
+            *cell = Some(num);
+        }
+        Ok(())
+    }
+
+    fn get_adjacent_numbers(&self, pos: Coord) -> Vec<Number> {
+        let mut numbers = HashSet::new();
+        for neighbour in self.0.neighbours8(pos) {
+            if let Some(num) = self.0[neighbour] {
+                numbers.insert(*num);
+            }
+        }
+
+        numbers.into_iter().collect::<Vec<Number>>()
+    }
+
+    /// Debug dump of the map, as saved in number_map.out
+    #[allow(dead_code)]
+    fn as_str(&self) -> String {
+        self.0
+            .map(|b| match *b {
+                None => '.',
+                Some(n) => n.value.to_string().chars().next().unwrap(),
+            })
+            .to_string()
+    }
+}
+
+aoc_common::example_tests!();
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_multibyte_symbols() {
+        // € takes three bytes but one column, so 1 touches it and both touch the *
+        let schematic = Puzzle::parse("€1*2\n....\n").unwrap();
+        assert_eq!(Puzzle::part_a(&schematic), Answer::from(3));
+        assert_eq!(Puzzle::part_b(&schematic), Answer::from(2));
+    }
+}
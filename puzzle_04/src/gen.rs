@@ -0,0 +1,80 @@
+//! Random scratchcards: `size` cards, each with 5 winning numbers and 8 numbers you have.
+//!
+//! No card wins copies of cards past the end, and the copies stay few enough for part
+//! B's count to fit in an i32.
+
+use std::fmt::Write;
+
+use aoc_common::gen::{distinct, Rng, RngExt, SliceRandom};
+
+const WINNING: usize = 5;
+const HAVE: usize = 8;
+/// Most cards one card can end up as, itself included
+const MAX_COPIES: u64 = 1_000_000;
+
+pub fn generate<R: Rng>(rng: &mut R, size: usize) -> String {
+    // working back from the last card, what each later card is worth is known
+    // by the time its earlier cards choose how many of them to win
+    let mut worth = vec![0; size];
+    let mut matches = vec![0; size];
+    for card in (0..size).rev() {
+        let wanted = match rng.random_bool(0.5) {
+            true => rng.random_range(1..=WINNING).min(size - 1 - card),
+            false => 0,
+        };
+        let won = |count: usize| 1 + worth[card + 1..=card + count].iter().sum::<u64>();
+        let count = (0..=wanted)
+            .rev()
+            .find(|&count| won(count) <= MAX_COPIES)
+            .unwrap_or(0);
+        (matches[card], worth[card]) = (count, won(count));
+    }
+
+    let width = size.to_string().len();
+    let list = |numbers: &[u32]| {
+        numbers
+            .iter()
+            .map(|n| format!("{n:>2}"))
+            .collect::<Vec<_>>()
+            .join(" ")
+    };
+    let mut input = String::new();
+    for (card, &count) in matches.iter().enumerate() {
+        let numbers = distinct(rng, WINNING + HAVE - count, |rng| rng.random_range(1..100));
+        let (winning, others) = numbers.split_at(WINNING);
+        let mut have = [&winning[..count], others].concat();
+        have.shuffle(rng);
+        let _ = writeln!(
+            input,
+            "Card {:>width$}: {} | {}",
+            card + 1,
+            list(winning),
+            list(&have)
+        );
+    }
+    input
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::gen::seeded;
+    use aoc_common::Solution;
+
+    use super::*;
+    use crate::Puzzle;
+
+    #[test]
+    fn test_generate() {
+        for seed in 0..20 {
+            let input = generate(&mut seeded(seed), 120);
+            let cards = Puzzle::parse(&input).unwrap();
+            assert_eq!(cards.len(), 120);
+            for card in cards.values() {
+                assert!(card.matching_numbers.len() <= WINNING);
+                assert!(card.idx as usize + card.matching_numbers.len() <= 120);
+            }
+            Puzzle::part_a(&cards);
+            Puzzle::part_b(&cards);
+        }
+    }
+}
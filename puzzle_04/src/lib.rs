@@ -0,0 +1,104 @@
+pub mod gen;
+
+use std::collections::BTreeMap;
+use std::collections::HashSet;
+
+use aoc_common::parse::{numbered, numbers};
+use aoc_common::{Answer, ParseError, Solution};
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input = BTreeMap<i32, Card>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let mut cards = BTreeMap::new();
+        for line in input.lines() {
+            let card = Card::from_line(input, line)?;
+            cards.insert(card.idx, card);
+        }
+        Ok(cards)
+    }
+
+    fn part_a(cards: &Self::Input) -> Answer {
+        let mut total_points = 0;
+        for card in cards.values() {
+            let mut points = 0;
+            for _ in &card.matching_numbers {
+                if points == 0 {
+                    points += 1;
+                } else {
+                    points *= 2
+                }
+            }
+            total_points += points;
+        }
+        total_points.into()
+    }
+
+    fn part_b(cards: &Self::Input) -> Answer {
+        let mut count_cache = BTreeMap::new();
+        let mut total = 0;
+        for card in cards.values() {
+            total += get_count(card, cards, &mut count_cache);
+        }
+        total.into()
+    }
+}
+
+#[derive(Debug)]
+pub struct Card {
+    idx: i32,
+    matching_numbers: Vec<i32>,
+}
+
+impl Card {
+    fn from_line(input: &str, line: &str) -> Result<Self, ParseError> {
+        let (card_idx, lists) = numbered(input, line, "Card")?;
+
+        let (winning, have) = lists
+            .split_once('|')
+            .ok_or_else(|| ParseError::missing(input, lists, "\"|\" between the number lists"))?;
+
+        let winning_numbers = numbers(input, winning)?
+            .into_iter()
+            .collect::<HashSet<i32>>();
+
+        let mut matching_numbers = Vec::new();
+        for num in numbers(input, have)? {
+            if winning_numbers.contains(&num) {
+                matching_numbers.push(num);
+            }
+        }
+
+        Ok(Self {
+            idx: card_idx,
+            matching_numbers,
+        })
+    }
+}
+
+fn get_count(
+    card: &Card,
+    cards: &BTreeMap<i32, Card>,
+    count_cache: &mut BTreeMap<i32, i32>,
+) -> i32 {
+    if let Some(val) = count_cache.get(&card.idx) {
+        return *val;
+    }
+    let mut total = 1; // one for this card
+    let num_matches = card.matching_numbers.len() as i32;
+    let my_idx = card.idx;
+    for i in 0..num_matches {
+        let next_idx = my_idx + 1 + i;
+        let other_card = cards.get(&next_idx);
+        // matches past the last card win nothing
+        if let Some(other_card) = other_card {
+            total += get_count(other_card, cards, count_cache);
+        }
+    }
+    count_cache.insert(my_idx, total);
+    total
+}
+
+aoc_common::example_tests!();
@@ -0,0 +1,96 @@
+//! Random almanacs: seed ranges and the seven maps from seed to location, all over the
+//! values below `100 * size`.
+//!
+//! Each map moves whole ranges around, so no two of its ranges overlap and every value
+//! comes from exactly one other. The seed ranges hold at most `10 * size` seeds between
+//! them, so that part B can still be checked one seed at a time.
+
+use std::fmt::Write;
+
+use aoc_common::gen::{split, Rng, RngExt, SliceRandom};
+
+pub(crate) const CATEGORIES: [&str; 8] = [
+    "seed",
+    "soil",
+    "fertilizer",
+    "water",
+    "light",
+    "temperature",
+    "humidity",
+    "location",
+];
+
+pub fn generate<R: Rng>(rng: &mut R, size: usize) -> String {
+    let size = size.max(1) as u64;
+    let values = 100 * size;
+
+    let pairs = rng.random_range(1..=4);
+    let seeds = (0..pairs)
+        .flat_map(|_| {
+            let start = rng.random_range(0..values);
+            let len = rng.random_range(1..=(10 * size / pairs).max(1));
+            [start, len]
+        })
+        .map(|n| n.to_string())
+        .collect::<Vec<_>>();
+    let mut input = format!("seeds: {}\n", seeds.join(" "));
+
+    for names in CATEGORIES.windows(2) {
+        let pieces = rng.random_range(2..=size as usize + 2);
+        let sources = split(rng, values, pieces);
+        // lay the pieces out again in a shuffled order to find where each one goes
+        let mut order = (0..pieces).collect::<Vec<_>>();
+        order.shuffle(rng);
+        if order.is_sorted() {
+            // a map that moves nothing would have no lines at all
+            order.reverse();
+        }
+        let mut dests = vec![0; pieces];
+        let mut next = 0;
+        for piece in order {
+            dests[piece] = next;
+            next += sources[piece].end - sources[piece].start;
+        }
+        let mut lines = std::iter::zip(sources, dests)
+            // a piece that stays put needs no line
+            .filter(|(source, dest)| source.start != *dest)
+            .map(|(source, dest)| format!("{dest} {} {}", source.start, source.end - source.start))
+            .collect::<Vec<_>>();
+        lines.shuffle(rng);
+        let _ = write!(input, "\n{}-to-{} map:\n", names[0], names[1]);
+        for line in lines {
+            let _ = writeln!(input, "{line}");
+        }
+    }
+    input
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::gen::seeded;
+    use aoc_common::Solution;
+
+    use super::*;
+    use crate::Puzzle;
+
+    #[test]
+    fn test_generate() {
+        for seed in 0..20 {
+            let input = generate(&mut seeded(seed), 10);
+            let almanac = Puzzle::parse(&input).unwrap();
+            assert_eq!(almanac.maps.len(), 7);
+            assert!(almanac.seeds.chunks(2).map(|pair| pair[1]).sum::<i64>() <= 100);
+            for map in &almanac.maps {
+                let mut ranges = map
+                    .ranges
+                    .iter()
+                    .map(|r| r.source_range())
+                    .collect::<Vec<_>>();
+                ranges.sort_by_key(|range| range.start);
+                assert!(ranges.windows(2).all(|pair| pair[0].end <= pair[1].start));
+            }
+            Puzzle::part_a(&almanac);
+            Puzzle::part_b(&almanac);
+        }
+    }
+}
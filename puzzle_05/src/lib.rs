@@ -0,0 +1,723 @@
+pub mod gen;
+mod piecewise;
+
+use std::collections::{HashMap, HashSet};
+use std::fmt::Write;
+use std::ops::{Range, RangeInclusive};
+
+use aoc_common::parse::{blocks, labelled, numbers};
+use aoc_common::{Answer, ParseError, Part, Solution};
+
+use piecewise::{Inverse, Piecewise};
+
+// Part B used to try every seed:
+// Result B: 10834440 in 2192.901100744s (brute force serial)
+// Result B: 10834440 in 880.852927328s (brute force parallel with rayon)
+// and now maps whole ranges of seeds at once, in microseconds
+
+/// The category the seeds line lists, where the chain of maps starts
+const START: &str = "seed";
+
+#[derive(Debug, Clone, Copy)]
+struct MapRange {
+    source_start: i64,
+    dest_start: i64,
+    len: i64,
+}
+
+impl MapRange {
+    #[cfg(test)]
+    fn resolve(&self, val: i64) -> Option<i64> {
+        if val >= self.source_start && val < self.source_start + self.len {
+            Some(self.dest_start + (val - self.source_start))
+        } else {
+            None
+        }
+    }
+
+    fn source_range(&self) -> Range<i64> {
+        self.source_start..(self.source_start + self.len)
+    }
+
+    /// The part of `range` this maps, if any
+    fn overlap(&self, range: &Range<i64>) -> Option<Range<i64>> {
+        let my_range = self.source_range();
+        let overlap = range.start.max(my_range.start)..range.end.min(my_range.end);
+        (!overlap.is_empty()).then_some(overlap)
+    }
+
+    /// Where this sends the values in `range`, which it must map all of
+    fn shift(&self, range: Range<i64>) -> Range<i64> {
+        let by = self.dest_start - self.source_start;
+        (range.start + by)..(range.end + by)
+    }
+}
+
+/// Converts values of one category to another, like "seed-to-soil"
+#[derive(Debug)]
+pub struct AlmanacMap {
+    from: String,
+    to: String,
+    ranges: Vec<MapRange>,
+}
+
+impl AlmanacMap {
+    fn from_tuples(from: &str, to: &str, tuples: &[(i64, i64, i64)]) -> Self {
+        let mut ranges = Vec::new();
+        for tup in tuples {
+            let (dest_start, source_start, range_len) = *tup;
+            ranges.push(MapRange {
+                source_start,
+                dest_start,
+                len: range_len,
+            })
+        }
+        Self {
+            from: from.to_string(),
+            to: to.to_string(),
+            ranges,
+        }
+    }
+
+    fn to_piecewise(&self) -> Piecewise {
+        let ranges = self
+            .ranges
+            .iter()
+            .map(|range| (range.source_range(), range.dest_start))
+            .collect::<Vec<_>>();
+        Piecewise::from_ranges(&ranges)
+    }
+
+    /// return the stored mapped value if it's in the map,
+    /// other wise just return the input
+    /// (only the tests look values up one map at a time now, to check `chain` against)
+    #[cfg(test)]
+    fn get(&self, key: i64) -> i64 {
+        for range in &self.ranges {
+            match range.resolve(key) {
+                Some(val) => return val,
+                None => continue,
+            }
+        }
+        key
+    }
+
+    /// Where the values in `range` end up, as ranges split wherever a different
+    /// `MapRange` takes over. Like `get`, the first `MapRange` holding a value wins.
+    fn get_range(&self, range: Range<i64>) -> Vec<Range<i64>> {
+        let mut unmapped = vec![range];
+        let mut mapped = Vec::new();
+        for map_range in &self.ranges {
+            let mut left = Vec::new();
+            for range in unmapped {
+                let Some(overlap) = map_range.overlap(&range) else {
+                    left.push(range);
+                    continue;
+                };
+                left.extend(
+                    [range.start..overlap.start, overlap.end..range.end]
+                        .into_iter()
+                        .filter(|rest| !rest.is_empty()),
+                );
+                mapped.push(map_range.shift(overlap));
+            }
+            unmapped = left;
+        }
+        mapped.extend(unmapped);
+        mapped
+    }
+}
+
+pub struct Almanac {
+    seeds: Vec<i64>,
+    /// In the order they chain in from seed, whatever order the file has them in
+    maps: Vec<AlmanacMap>,
+    /// Every map one after another, in one
+    chain: Piecewise,
+    inverse: Inverse,
+}
+
+impl Almanac {
+    /// The seeds read as part B does, in pairs of a start and a length
+    fn seed_ranges(&self) -> Result<Vec<(i64, i64)>, String> {
+        self.seeds
+            .chunks(2)
+            .map(|pair| match *pair {
+                [start, len] if len >= 0 && start.checked_add(len).is_some() => Ok((start, len)),
+                [start, len] => Err(format!(
+                    "{len} seeds from {start} is not a range of seeds within i64"
+                )),
+                _ => Err(format!("seed {} has no length after it", pair[0])),
+            })
+            .collect()
+    }
+
+    /// The categories the whole chain of maps goes from and to, seed and location
+    fn chain_ends(&self) -> (&str, &str) {
+        let to = self.maps.last().map_or(START, |map| &map.to);
+        (START, to)
+    }
+
+    /// Every category, in the order the maps convert them
+    pub fn categories(&self) -> Vec<&str> {
+        let mut categories = vec![START];
+        categories.extend(self.maps.iter().map(|map| map.to.as_str()));
+        categories
+    }
+
+    /// What `value` of the category `from` is as a `to`. Back up the chain, towards seed,
+    /// any number of values can convert to the one asked about, and all are given.
+    pub fn convert(&self, from: &str, to: &str, value: i64) -> Result<Vec<i64>, String> {
+        let categories = self.categories();
+        let position = |category| {
+            categories
+                .iter()
+                .position(|&known| known == category)
+                .ok_or_else(|| {
+                    format!(
+                        "no {category} category, the almanac has {}",
+                        categories.join(", ")
+                    )
+                })
+        };
+        let (from_at, to_at) = (position(from)?, position(to)?);
+        let compose = |maps: &[AlmanacMap]| {
+            maps.iter().fold(Piecewise::identity(), |chain, map| {
+                chain.then(&map.to_piecewise())
+            })
+        };
+        Ok(match from_at <= to_at {
+            true => {
+                let found = compose(&self.maps[from_at..to_at]).get(value);
+                vec![found.ok_or_else(|| format!("{from} {value} is no {to} within i64"))?]
+            }
+            false => compose(&self.maps[to_at..from_at]).inverse().get(value),
+        })
+    }
+
+    pub fn location(&self, seed: i64) -> i64 {
+        // parsing keeps every range within i64, so no seed goes beyond it
+        self.chain
+            .get(seed)
+            .expect("the maps keep every value within i64")
+    }
+
+    /// Every seed that ends up at `location`, lowest first
+    pub fn seeds_for(&self, location: i64) -> Vec<i64> {
+        self.inverse.get(location)
+    }
+
+    /// The ranges of seeds that end up somewhere in `locations`
+    pub fn seeds_for_range(&self, locations: RangeInclusive<i64>) -> Vec<RangeInclusive<i64>> {
+        self.inverse.get_range(locations)
+    }
+
+    /// The lowest location for any of the `len` seeds from `start`, found by pushing
+    /// the whole range through the maps, or None if there are no seeds
+    fn min_for_range(&self, start: i64, len: i64) -> Option<i64> {
+        let seeds = start..start + len;
+        let ranges = self.maps.iter().fold(vec![seeds], |ranges, map| {
+            ranges
+                .into_iter()
+                .filter(|range| !range.is_empty())
+                .flat_map(|range| map.get_range(range))
+                .collect()
+        });
+        ranges
+            .iter()
+            .filter(|range| !range.is_empty())
+            .map(|range| range.start)
+            .min()
+    }
+}
+
+fn parse_almanac(input: &str) -> Result<Almanac, ParseError> {
+    let blocks = blocks(input);
+    let mut blocks = blocks.iter();
+    let seeds_block = blocks.next().map_or(&[][..], |block| &block[..]);
+    let seeds_line = seeds_block.first().copied().unwrap_or("");
+    if let Some(extra) = seeds_block.get(1) {
+        return Err(ParseError::new(
+            input,
+            extra,
+            "a blank line between the seeds and the first map",
+        ));
+    }
+    let seeds = parse_seeds(input, seeds_line)?;
+
+    let mut maps = Vec::new();
+    let mut headers = Vec::new();
+    for block in blocks {
+        let header = block[0];
+        let name = header
+            .trim()
+            .strip_suffix(" map:")
+            .ok_or_else(|| ParseError::new(input, header, "\"NAME map:\""))?;
+        let (from, to) = name
+            .split_once("-to-")
+            .ok_or_else(|| ParseError::new(input, name, "\"SOURCE-to-DESTINATION\""))?;
+        let mut tuples = Vec::new();
+        for line in &block[1..] {
+            let [dest, source, len] = numbers::<i64>(input, line)?[..] else {
+                return Err(ParseError::new(
+                    input,
+                    line,
+                    "three numbers: destination, source and length",
+                ));
+            };
+            if len < 0 || source.checked_add(len).is_none() || dest.checked_add(len).is_none() {
+                return Err(ParseError::new(
+                    input,
+                    line,
+                    "a length of 0 or more that keeps both ranges within i64",
+                ));
+            }
+            let overlapping = tuples.iter().position(|&(_, other, other_len)| {
+                source.max(other) < (source + len).min(other + other_len)
+            });
+            if let Some(other) = overlapping {
+                return Err(ParseError::new(
+                    input,
+                    line,
+                    format!(
+                        "sources not already mapped by \"{}\"",
+                        block[1 + other].trim()
+                    ),
+                ));
+            }
+            tuples.push((dest, source, len));
+        }
+        maps.push(AlmanacMap::from_tuples(from, to, &tuples));
+        headers.push(header);
+    }
+    let maps = chain_maps(input, maps, &headers)?;
+
+    let chain = maps.iter().fold(Piecewise::identity(), |chain, map| {
+        chain.then(&map.to_piecewise())
+    });
+    let inverse = chain.inverse();
+    Ok(Almanac {
+        seeds,
+        maps,
+        chain,
+        inverse,
+    })
+}
+
+/// The seeds, of which there must be at least one. Part B reads them in pairs, and
+/// checks those when it does.
+fn parse_seeds(input: &str, line: &str) -> Result<Vec<i64>, ParseError> {
+    let seeds = numbers(input, labelled(input, line, "seeds")?)?;
+    if seeds.is_empty() {
+        return Err(ParseError::missing(input, line, "at least one seed"));
+    }
+    Ok(seeds)
+}
+
+/// Put the maps in the order they chain in from seed, checking that each category
+/// has one map from it at most, that no map leads back to a category already
+/// converted, and that every map is part of the chain
+fn chain_maps(
+    input: &str,
+    maps: Vec<AlmanacMap>,
+    headers: &[&str],
+) -> Result<Vec<AlmanacMap>, ParseError> {
+    let mut by_source = HashMap::new();
+    for (i, map) in maps.iter().enumerate() {
+        if let Some(first) = by_source.insert(map.from.as_str(), i) {
+            return Err(ParseError::new(
+                input,
+                headers[i].trim(),
+                format!(
+                    "one map from {} at most, \"{}\" is one already",
+                    map.from,
+                    headers[first].trim()
+                ),
+            ));
+        }
+    }
+
+    let mut order = Vec::new();
+    let mut seen = HashSet::from([START]);
+    let mut category = START;
+    while let Some(&i) = by_source.get(category) {
+        let map = &maps[i];
+        if !seen.insert(map.to.as_str()) {
+            return Err(ParseError::new(
+                input,
+                headers[i].trim(),
+                format!("a map to a new category, {} is converted already", map.to),
+            ));
+        }
+        order.push(i);
+        category = &map.to;
+    }
+    if let Some(i) = (0..maps.len()).find(|i| !order.contains(i)) {
+        return Err(ParseError::new(
+            input,
+            headers[i].trim(),
+            format!(
+                "a map from {START} or a category it converts to, not from {}",
+                maps[i].from
+            ),
+        ));
+    }
+
+    let mut maps = maps.into_iter().map(Some).collect::<Vec<_>>();
+    Ok(order
+        .into_iter()
+        .map(|i| maps[i].take().expect("each map comes once in the chain"))
+        .collect())
+}
+
+/// What a value of one category is as another, for `aoc run --from --to --value`.
+/// The outer error is for an almanac that doesn't parse, the inner for a category
+/// it doesn't have.
+#[allow(clippy::type_complexity)]
+pub fn convert(
+    input: &str,
+    from: &str,
+    to: &str,
+    value: i64,
+) -> Result<Result<Vec<i64>, String>, ParseError> {
+    Ok(parse_almanac(input)?.convert(from, to, value))
+}
+
+/// The maps composed into one table, for `aoc run --explain`. Part B shows it the other
+/// way round, by location, as the lowest locations are what it is after.
+pub fn explain(input: &str, part: Part) -> Result<String, ParseError> {
+    let almanac = parse_almanac(input)?;
+    let (from, to) = almanac.chain_ends();
+    let mut out = String::new();
+    let _ = match part {
+        Part::A => write!(out, "{from}-to-{to} map:\n{}", almanac.chain),
+        Part::B => write!(out, "{to}-to-{from} map:\n{}", almanac.inverse),
+    };
+    Ok(out)
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input = Almanac;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_almanac(input)
+    }
+
+    fn part_a(almanac: &Self::Input) -> Answer {
+        let mut results = Vec::new();
+        for seed in almanac.seeds.iter().copied() {
+            results.push(almanac.location(seed));
+        }
+        let min_loc = results.iter().reduce(|acc, n| acc.min(n)).unwrap();
+        (*min_loc).into()
+    }
+
+    fn part_b(almanac: &Self::Input) -> Answer {
+        let ranges = match almanac.seed_ranges() {
+            Ok(ranges) => ranges,
+            Err(err) => return err.into(),
+        };
+        ranges
+            .into_iter()
+            .filter_map(|(start, len)| almanac.min_for_range(start, len))
+            .min()
+            .map_or_else(|| "no seeds in any of the ranges".into(), Answer::from)
+    }
+}
+
+aoc_common::example_tests!();
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use aoc_common::differential::{check, Differential};
+    use aoc_common::known::PartAnswers;
+    use aoc_common::Part;
+    use proptest::prelude::*;
+
+    use super::*;
+    use crate::gen::CATEGORIES;
+
+    /// seed-to-soil from the example: 98..100 goes to 50..52, 50..98 to 52..100
+    fn seed_to_soil() -> AlmanacMap {
+        AlmanacMap::from_tuples("seed", "soil", &[(50, 98, 2), (52, 50, 48)])
+    }
+
+    #[test]
+    #[allow(clippy::single_range_in_vec_init)] // a single range, not its values
+    fn test_get_range_disjoint() {
+        let map = seed_to_soil();
+        assert_eq!(map.get_range(10..20), [10..20]);
+        assert_eq!(map.get_range(100..120), [100..120]);
+        // ends just before a map range starts
+        assert_eq!(map.get_range(40..50), [40..50]);
+    }
+
+    #[test]
+    fn test_get_range_partial() {
+        let map = seed_to_soil();
+        // runs into the start of 50..98
+        assert_eq!(map.get_range(45..55), [52..57, 45..50]);
+        // runs off the end of 98..100
+        assert_eq!(map.get_range(99..103), [51..52, 100..103]);
+        // across both map ranges
+        assert_eq!(map.get_range(96..100), [50..52, 98..100]);
+    }
+
+    #[test]
+    #[allow(clippy::single_range_in_vec_init)] // a single range, not its values
+    fn test_get_range_nested() {
+        let map = seed_to_soil();
+        // inside a map range
+        assert_eq!(map.get_range(60..70), [62..72]);
+        // around both map ranges, so split in four
+        assert_eq!(map.get_range(0..200), [50..52, 52..100, 0..50, 100..200]);
+    }
+
+    #[test]
+    fn test_get_range_first_wins() {
+        // 10..20 is claimed by both, and get uses the first
+        let map = AlmanacMap::from_tuples("a", "b", &[(100, 10, 10), (200, 0, 30)]);
+        assert_eq!(map.get_range(5..25), [100..110, 205..210, 220..225]);
+        for seed in 5..25 {
+            let to = map.get(seed);
+            assert!(map.get_range(5..25).iter().any(|range| range.contains(&to)));
+        }
+    }
+
+    #[test]
+    #[allow(clippy::single_range_in_vec_init)] // a single range, not its values
+    fn test_chain() {
+        let input = include_str!("../examples/01.txt");
+        let almanac = Puzzle::parse(input).unwrap();
+        assert_eq!(almanac.chain_ends(), ("seed", "location"));
+        for seed in 0..120 {
+            let folded = almanac.maps.iter().fold(seed, |acc, m| m.get(acc));
+            assert_eq!(almanac.location(seed), folded, "{seed}");
+            assert!(almanac.seeds_for(folded).contains(&seed));
+        }
+        // seed 82 is the one at location 46
+        assert_eq!(almanac.seeds_for(46), [82]);
+        assert_eq!(almanac.seeds_for_range(46..=46), [82..=82]);
+        assert_eq!(almanac.seeds_for_range(0..=2), [26..=27, 70..=70]);
+        let explained = explain(input, Part::A).unwrap();
+        assert!(explained.starts_with("seed-to-location map:\n"));
+        assert!(explain(input, Part::B)
+            .unwrap()
+            .starts_with("location-to-seed map:\n"));
+    }
+
+    #[test]
+    fn test_maps_out_of_order() {
+        let input = include_str!("../examples/01.txt");
+        let mut blocks = input.split("\n\n").collect::<Vec<_>>();
+        blocks[1..].reverse();
+        let shuffled = blocks.join("\n\n") + "\n";
+        let almanac = Puzzle::parse(&shuffled).unwrap();
+        assert_eq!(
+            almanac.categories(),
+            [
+                "seed",
+                "soil",
+                "fertilizer",
+                "water",
+                "light",
+                "temperature",
+                "humidity",
+                "location"
+            ]
+        );
+        assert_eq!(Puzzle::part_a(&almanac), 35.into());
+        assert_eq!(Puzzle::part_b(&almanac), 46.into());
+    }
+
+    #[test]
+    fn test_convert() {
+        let almanac = Puzzle::parse(include_str!("../examples/01.txt")).unwrap();
+        // from the puzzle: seed 79, soil 81, fertilizer 81, water 81, light 74,
+        // temperature 78, humidity 78, location 82
+        assert_eq!(almanac.convert("seed", "soil", 79), Ok(vec![81]));
+        assert_eq!(almanac.convert("soil", "humidity", 81), Ok(vec![78]));
+        assert_eq!(almanac.convert("light", "light", 74), Ok(vec![74]));
+        assert_eq!(almanac.convert("location", "seed", 82), Ok(vec![79]));
+        assert!(almanac
+            .convert("humidity", "soil", 78)
+            .unwrap()
+            .contains(&81));
+        assert_eq!(
+            almanac.convert("seed", "dirt", 1),
+            Err(
+                "no dirt category, the almanac has seed, soil, fertilizer, water, light, \
+                 temperature, humidity, location"
+                    .to_string()
+            )
+        );
+    }
+
+    #[test]
+    fn test_convert_ends_of_i64() {
+        let almanac = Puzzle::parse(include_str!("../examples/01.txt")).unwrap();
+        for value in [i64::MIN, i64::MAX] {
+            assert_eq!(almanac.convert("seed", "location", value), Ok(vec![value]));
+            assert_eq!(almanac.convert("location", "seed", value), Ok(vec![value]));
+        }
+        let input = "seeds: 1 2\n\nseed-to-soil map:\n9223372036854775797 0 10\n";
+        let almanac = Puzzle::parse(input).unwrap();
+        assert_eq!(almanac.convert("seed", "soil", 9), Ok(vec![i64::MAX - 1]));
+        assert_eq!(
+            almanac.convert("soil", "seed", i64::MAX - 1),
+            Ok(vec![9, i64::MAX - 1])
+        );
+        // running past the end of i64 is caught while parsing
+        let err = parse_error("seeds: 1 2\n\nseed-to-soil map:\n0 9223372036854775800 10\n");
+        assert_eq!(err.token, "0 9223372036854775800 10");
+        assert_eq!(
+            parse_error("seeds: 1 2\n\nseed-to-soil map:\n0 5 -1\n").line,
+            4
+        );
+    }
+
+    fn parse_error(input: &str) -> ParseError {
+        Puzzle::parse(input).err().unwrap()
+    }
+
+    #[test]
+    fn test_bad_seeds() {
+        let err = parse_error("seeds:\n\nseed-to-soil map:\n50 98 2\n");
+        assert_eq!((err.line, err.expected.as_str()), (1, "at least one seed"));
+        assert_eq!(parse_error("seeds: 79 x\n").token, "x");
+        // a map straight after the seeds, without a blank line
+        let err = parse_error("seeds: 79 14\nseed-to-soil map:\n50 98 2\n");
+        assert_eq!((err.line, err.token.as_str()), (2, "seed-to-soil map:"));
+    }
+
+    #[test]
+    fn test_seed_ranges() {
+        let parts = |seeds: &str| {
+            let input = format!("seeds: {seeds}\n\nseed-to-soil map:\n50 98 2\n");
+            let almanac = Puzzle::parse(&input).unwrap();
+            (Puzzle::part_a(&almanac), Puzzle::part_b(&almanac))
+        };
+        // only part B needs the seeds in pairs
+        assert_eq!(
+            parts("79 14 55"),
+            (14.into(), "seed 55 has no length after it".into())
+        );
+        assert_eq!(parts("98 0 99 1"), (0.into(), 51.into()));
+        assert_eq!(
+            parts("79 0"),
+            (0.into(), "no seeds in any of the ranges".into())
+        );
+        assert_eq!(
+            parts("9223372036854775800 10"),
+            (
+                10.into(),
+                "10 seeds from 9223372036854775800 is not a range of seeds within i64".into()
+            )
+        );
+    }
+
+    #[test]
+    fn test_overlapping_ranges() {
+        let err = parse_error("seeds: 1 2\n\nseed-to-soil map:\n50 98 2\n0 90 10\n");
+        assert_eq!((err.line, err.token.as_str()), (5, "0 90 10"));
+        assert_eq!(err.expected, "sources not already mapped by \"50 98 2\"");
+        // touching is fine
+        assert!(Puzzle::parse("seeds: 1 2\n\nseed-to-soil map:\n50 98 2\n0 88 10\n").is_ok());
+    }
+
+    #[test]
+    fn test_bad_chains() {
+        let err = parse_error("seeds: 1 2\n\nseed soil map:\n1 2 3\n");
+        assert_eq!(err.token, "seed soil");
+        // two maps from soil
+        let err = parse_error(
+            "seeds: 1 2\n\nseed-to-soil map:\n\nsoil-to-water map:\n\nsoil-to-light map:\n",
+        );
+        assert_eq!((err.line, err.token.as_str()), (7, "soil-to-light map:"));
+        // back to seed
+        let err = parse_error("seeds: 1 2\n\nseed-to-soil map:\n\nsoil-to-seed map:\n");
+        assert_eq!((err.line, err.token.as_str()), (5, "soil-to-seed map:"));
+        // nothing converts to water
+        let err = parse_error("seeds: 1 2\n\nseed-to-soil map:\n\nwater-to-light map:\n");
+        assert_eq!((err.line, err.token.as_str()), (5, "water-to-light map:"));
+        // a loop off to the side
+        let err = parse_error(
+            "seeds: 1 2\n\nseed-to-soil map:\n\nwater-to-light map:\n\nlight-to-water map:\n",
+        );
+        assert_eq!(err.line, 5);
+    }
+
+    #[test]
+    fn test_min_for_range() {
+        let almanac = Puzzle::parse(include_str!("../examples/01.txt")).unwrap();
+        assert_eq!(almanac.min_for_range(79, 14), Some(46));
+        assert_eq!(almanac.min_for_range(55, 13), Some(56));
+        assert_eq!(almanac.min_for_range(79, 0), None);
+    }
+
+    /// Seed ranges, and for each map its ranges as the gap before the source, the length
+    /// and the destination. The sources come one after another, so they never overlap.
+    type Rows = (Vec<(i64, i64)>, Vec<Vec<(i64, i64, i64)>>);
+
+    fn almanac(seeds: &[i64], maps: &[Vec<(i64, i64, i64)>]) -> String {
+        let seeds = seeds
+            .iter()
+            .map(|seed| seed.to_string())
+            .collect::<Vec<_>>();
+        let mut input = format!("seeds: {}\n", seeds.join(" "));
+        for (i, ranges) in maps.iter().enumerate() {
+            input += &format!("\n{}-to-{} map:\n", CATEGORIES[i], CATEGORIES[i + 1]);
+            let mut source = 0;
+            for &(gap, len, dest) in ranges {
+                source += gap;
+                input += &format!("{dest} {source} {len}\n");
+                source += len;
+            }
+        }
+        input
+    }
+
+    fn input((seeds, maps): &Rows) -> String {
+        let seeds = seeds
+            .iter()
+            .flat_map(|&(start, len)| [start, len])
+            .collect::<Vec<_>>();
+        almanac(&seeds, maps)
+    }
+
+    #[test]
+    fn test_part_b_agrees() {
+        let seeds = prop::collection::vec((0i64..60, 1i64..10), 1..4);
+        let map = prop::collection::vec((0i64..10, 1i64..10, 0i64..60), 1..5);
+        let maps = prop::collection::vec(map, 1..8);
+        check(
+            Path::new(env!("CARGO_MANIFEST_DIR")),
+            (seeds, maps),
+            Differential {
+                input,
+                // every seed in the ranges, one map and one seed at a time
+                trusted: |rows: &Rows| {
+                    let parsed = Puzzle::parse(&input(rows)).unwrap();
+                    let lowest = rows
+                        .0
+                        .iter()
+                        .flat_map(|&(start, len)| start..start + len)
+                        .map(|seed| parsed.maps.iter().fold(seed, |acc, m| m.get(acc)))
+                        .min();
+                    PartAnswers {
+                        a: None,
+                        b: lowest.map(Answer::from),
+                    }
+                },
+                tested: |rows: &Rows| PartAnswers {
+                    a: None,
+                    b: Puzzle::solve(&input(rows), Part::B).ok(),
+                },
+            },
+        );
+    }
+}
@@ -0,0 +1,351 @@
+//! Maps made of ranges that each shift their values by a constant, normalized so that
+//! they can be composed, inverted and printed.
+//!
+//! The bounds are kept as i128, so that a range can run up to and including
+//! `i64::MAX`, and shifting a range past either end of i64 doesn't overflow.
+
+use std::fmt;
+use std::ops::{Range, RangeInclusive};
+
+/// The lowest value mapped
+const MIN: i128 = i64::MIN as i128;
+/// One past the highest value mapped, `i64::MAX`
+const END: i128 = i64::MAX as i128 + 1;
+
+/// `start..end` goes to `start + shift..end + shift`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Segment {
+    start: i128,
+    end: i128,
+    shift: i128,
+}
+
+impl Segment {
+    fn image(&self) -> Range<i128> {
+        (self.start + self.shift)..(self.end + self.shift)
+    }
+
+    /// The part of this sent somewhere in `within`, if any
+    fn clip(&self, within: Range<i128>) -> Option<Segment> {
+        let start = self.start.max(within.start - self.shift);
+        let end = self.end.min(within.end - self.shift);
+        (start < end).then_some(Segment {
+            start,
+            end,
+            ..*self
+        })
+    }
+}
+
+/// A map over the i64 values, as segments sorted by where they start, with no two
+/// touching neighbours shifting by the same amount. Values no range maps get identity
+/// segments, and only values that would be sent outside i64 are left without one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Piecewise {
+    segments: Vec<Segment>,
+}
+
+impl Piecewise {
+    pub fn identity() -> Self {
+        Self::normalized([Segment {
+            start: MIN,
+            end: END,
+            shift: 0,
+        }])
+    }
+
+    /// The map sending each source range to where its destination starts. Where ranges
+    /// overlap, the first one holding a value wins.
+    pub fn from_ranges(ranges: &[(Range<i64>, i64)]) -> Self {
+        let ranges = ranges
+            .iter()
+            .map(|(range, dest)| {
+                let source = range.start as i128..range.end as i128;
+                let shift = *dest as i128 - source.start;
+                (source, shift)
+            })
+            .collect::<Vec<_>>();
+        let mut cuts = ranges
+            .iter()
+            .flat_map(|(range, _)| [range.start, range.end])
+            .chain([MIN, END])
+            .collect::<Vec<_>>();
+        cuts.sort_unstable();
+        cuts.dedup();
+        Self::normalized(cuts.windows(2).filter_map(|cut| {
+            let shift = ranges
+                .iter()
+                .find(|(range, _)| range.contains(&cut[0]))
+                .map_or(0, |&(_, shift)| shift);
+            let segment = Segment {
+                start: cut[0],
+                end: cut[1],
+                shift,
+            };
+            segment.clip(MIN..END)
+        }))
+    }
+
+    /// Merge neighbouring segments that shift by the same amount
+    fn normalized(segments: impl IntoIterator<Item = Segment>) -> Self {
+        let mut merged: Vec<Segment> = Vec::new();
+        for segment in segments {
+            match merged.last_mut() {
+                Some(last) if last.shift == segment.shift && last.end == segment.start => {
+                    last.end = segment.end
+                }
+                _ => merged.push(segment),
+            }
+        }
+        Self { segments: merged }
+    }
+
+    /// Index of the first segment that ends after `value`
+    fn position(&self, value: i128) -> usize {
+        self.segments
+            .partition_point(|segment| segment.end <= value)
+    }
+
+    /// Where `value` goes, or None if that is outside i64
+    pub fn get(&self, value: i64) -> Option<i64> {
+        let value = value as i128;
+        let segment = self
+            .segments
+            .get(self.position(value))
+            .filter(|segment| segment.start <= value)?;
+        Some((value + segment.shift) as i64)
+    }
+
+    /// This map followed by `next`
+    pub fn then(&self, next: &Piecewise) -> Piecewise {
+        let mut segments = Vec::new();
+        for segment in &self.segments {
+            let image = segment.image();
+            for after in &next.segments[next.position(image.start)..] {
+                if after.start >= image.end {
+                    break;
+                }
+                segments.push(Segment {
+                    start: image.start.max(after.start) - segment.shift,
+                    end: image.end.min(after.end) - segment.shift,
+                    shift: segment.shift + after.shift,
+                });
+            }
+        }
+        Self::normalized(segments)
+    }
+
+    pub fn inverse(&self) -> Inverse {
+        let mut segments = self
+            .segments
+            .iter()
+            .map(|segment| {
+                let image = segment.image();
+                Segment {
+                    start: image.start,
+                    end: image.end,
+                    shift: -segment.shift,
+                }
+            })
+            .collect::<Vec<_>>();
+        segments.sort_by_key(|segment| (segment.start, segment.end));
+        Inverse { segments }
+    }
+}
+
+/// Which values a [`Piecewise`] sends where, looked up from where they end up. Several
+/// segments can land on the same values, so answers come as lists.
+#[derive(Debug, Clone)]
+pub struct Inverse {
+    /// By where they land, so `start..end` is the image and `shift` takes it back
+    segments: Vec<Segment>,
+}
+
+impl Inverse {
+    /// Every value sent to `value`, lowest first
+    pub fn get(&self, value: i64) -> Vec<i64> {
+        self.get_range(value..=value)
+            .into_iter()
+            .map(|range| *range.start())
+            .collect()
+    }
+
+    /// The values sent into `range`, as sorted ranges that neither overlap nor touch
+    pub fn get_range(&self, range: RangeInclusive<i64>) -> Vec<RangeInclusive<i64>> {
+        let (first, last) = (*range.start() as i128, *range.end() as i128);
+        let mut found = self
+            .segments
+            .iter()
+            .take_while(|segment| segment.start <= last)
+            .filter_map(|segment| {
+                let start = first.max(segment.start);
+                let end = (last + 1).min(segment.end);
+                (start < end).then(|| (start + segment.shift)..(end + segment.shift))
+            })
+            .collect::<Vec<_>>();
+        found.sort_by_key(|range| range.start);
+        let mut merged: Vec<Range<i128>> = Vec::new();
+        for range in found {
+            match merged.last_mut() {
+                Some(last) if range.start <= last.end => last.end = last.end.max(range.end),
+                _ => merged.push(range),
+            }
+        }
+        // every segment's values are i64s, so the ranges of them are too
+        merged
+            .into_iter()
+            .map(|range| range.start as i64..=(range.end - 1) as i64)
+            .collect()
+    }
+}
+
+/// `start..end`, leaving out the ends at the ends of i64
+fn show_range(start: i128, end: i128) -> String {
+    let start = if start == MIN {
+        String::new()
+    } else {
+        start.to_string()
+    };
+    let end = if end == END {
+        String::new()
+    } else {
+        end.to_string()
+    };
+    format!("{start}..{end}")
+}
+
+/// One row per segment: where from, where to, and by how much
+fn write_table(f: &mut fmt::Formatter<'_>, segments: &[Segment]) -> fmt::Result {
+    let rows = segments
+        .iter()
+        .map(|segment| {
+            let shift = match segment.shift {
+                0 => "same".to_string(),
+                shift => format!("{shift:+}"),
+            };
+            let to = match segment.shift {
+                0 => String::new(),
+                _ => show_range(segment.start + segment.shift, segment.end + segment.shift),
+            };
+            (show_range(segment.start, segment.end), to, shift)
+        })
+        .collect::<Vec<_>>();
+    let from_width = rows
+        .iter()
+        .map(|(from, _, _)| from.len())
+        .max()
+        .unwrap_or(0);
+    let to_width = rows.iter().map(|(_, to, _)| to.len()).max().unwrap_or(0);
+    for (from, to, shift) in rows {
+        writeln!(f, "{from:>from_width$} -> {to:<to_width$} {shift}")?;
+    }
+    Ok(())
+}
+
+impl fmt::Display for Piecewise {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_table(f, &self.segments)
+    }
+}
+
+impl fmt::Display for Inverse {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_table(f, &self.segments)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// seed-to-soil from the example
+    fn seed_to_soil() -> Piecewise {
+        Piecewise::from_ranges(&[(98..100, 50), (50..98, 52)])
+    }
+
+    #[test]
+    fn test_from_ranges() {
+        let map = seed_to_soil();
+        assert_eq!(map.segments.len(), 4);
+        assert_eq!(
+            [0, 49, 50, 97, 98, 99, 100].map(|v| map.get(v).unwrap()),
+            [0, 49, 52, 99, 50, 51, 100]
+        );
+        // the first range wins where two overlap, and equal shifts merge
+        let map = Piecewise::from_ranges(&[(0..10, 5), (5..20, 6), (20..30, 21)]);
+        assert_eq!(map, Piecewise::from_ranges(&[(0..10, 5), (10..30, 11)]));
+    }
+
+    #[test]
+    fn test_then() {
+        let map = seed_to_soil();
+        let back = Piecewise::from_ranges(&[(50..52, 98), (52..100, 50)]);
+        assert_eq!(map.then(&back), Piecewise::identity());
+        assert_eq!(Piecewise::identity().then(&map), map);
+        let twice = map.then(&map);
+        for value in 0..120 {
+            assert_eq!(
+                twice.get(value),
+                map.get(map.get(value).unwrap()),
+                "{value}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_inverse() {
+        let inverse = seed_to_soil().inverse();
+        // 50 and 51 move up, so only 98 and 99 moving down land there
+        assert_eq!(inverse.get(50), [98]);
+        assert_eq!(inverse.get(99), [97]);
+        assert_eq!(inverse.get(5), [5]);
+        // 98 and 99 are moved up from 96 and 97, and nothing is sent to themselves
+        assert_eq!(inverse.get(98), [96]);
+        assert_eq!(inverse.get_range(45..=59), [45..=57, 98..=99]);
+        // 10..20 is both left alone and landed on by 0..10
+        let inverse = Piecewise::from_ranges(&[(0..10, 10)]).inverse();
+        assert_eq!(inverse.get(15), [5, 15]);
+        assert_eq!(inverse.get_range(0..=29), [0..=29]);
+        assert_eq!(inverse.get_range(0..=9), []);
+    }
+
+    #[test]
+    fn test_ends_of_i64() {
+        let identity = Piecewise::identity();
+        assert_eq!(identity.get(i64::MIN), Some(i64::MIN));
+        assert_eq!(identity.get(i64::MAX), Some(i64::MAX));
+        assert_eq!(identity.inverse().get(i64::MAX), [i64::MAX]);
+        assert_eq!(identity.inverse().get(i64::MIN), [i64::MIN]);
+
+        // 0..10 would go up to past i64::MAX, so 6..10 go nowhere
+        let up = Piecewise::from_ranges(&[(0..10, i64::MAX - 5)]);
+        assert_eq!(up.get(5), Some(i64::MAX));
+        assert_eq!(up.get(6), None);
+        assert_eq!(up.get(i64::MAX), Some(i64::MAX));
+        assert_eq!(up.inverse().get(i64::MAX), [5, i64::MAX]);
+        assert_eq!(
+            up.inverse().get_range(i64::MAX - 1..=i64::MAX),
+            [4..=5, i64::MAX - 1..=i64::MAX]
+        );
+
+        // the values going nowhere stay that way in a composed map
+        let twice = up.then(&up);
+        assert_eq!(twice.get(5), Some(i64::MAX));
+        assert_eq!(twice.get(6), None);
+
+        let down = Piecewise::from_ranges(&[(i64::MIN..i64::MIN + 3, -1)]);
+        assert_eq!(down.get(i64::MIN), Some(-1));
+        assert_eq!(down.get(i64::MIN + 3), Some(i64::MIN + 3));
+        assert_eq!(down.inverse().get(-1), [i64::MIN, -1]);
+        assert_eq!(down.inverse().get(i64::MIN), []);
+    }
+
+    #[test]
+    fn test_display() {
+        let table = seed_to_soil().to_string();
+        assert_eq!(
+            table,
+            "   ..50 ->         same\n 50..98 -> 52..100 +2\n98..100 -> 50..52  -48\n  100.. ->         same\n"
+        );
+    }
+}
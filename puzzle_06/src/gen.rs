@@ -0,0 +1,46 @@
+//! Random race sheets: `size` races, each with a record that can be beaten.
+//!
+//! Part B reads all the times as one number, and all the records as another, so sheets
+//! of more than four races stop fitting in an i64.
+
+use aoc_common::gen::{Rng, RngExt};
+
+pub fn generate<R: Rng>(rng: &mut R, size: usize) -> String {
+    let (times, records): (Vec<i64>, Vec<i64>) = (0..size)
+        .map(|_| {
+            let time = rng.random_range(2..100);
+            let furthest = (time / 2) * (time - time / 2);
+            (time, rng.random_range(0..furthest))
+        })
+        .unzip();
+
+    // line the columns up the way the puzzle does
+    let (mut time_line, mut distance_line) = ("Time:    ".to_string(), "Distance:".to_string());
+    for (time, record) in times.iter().zip(&records) {
+        let width = time.to_string().len().max(record.to_string().len()) + 2;
+        time_line += &format!("{time:>width$}");
+        distance_line += &format!("{record:>width$}");
+    }
+    format!("{time_line}\n{distance_line}\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::gen::seeded;
+    use aoc_common::Solution;
+
+    use super::*;
+    use crate::Puzzle;
+
+    #[test]
+    fn test_generate() {
+        for seed in 0..20 {
+            let input = generate(&mut seeded(seed), 3);
+            let sheet = Puzzle::parse(&input).unwrap();
+            assert_eq!(sheet.races.len(), 3);
+            assert!(sheet.races.iter().all(|race| race.get_win_ways_count() > 0));
+            Puzzle::part_a(&sheet);
+            Puzzle::part_b(&sheet);
+        }
+    }
+}
@@ -0,0 +1,169 @@
+pub mod gen;
+
+use aoc_common::parse::{joined_number, labelled, numbers};
+use aoc_common::{Answer, ParseError, Solution};
+
+#[derive(Debug)]
+struct Race {
+    time: i64,
+    distance: i64,
+}
+
+impl Race {
+    fn get_win_ways_count(&self) -> i64 {
+        let mut count = 0;
+        for i in 0..self.time {
+            let dist = i * (self.time - i);
+            if dist > self.distance {
+                count += 1
+            }
+        }
+        count
+    }
+
+    #[cfg(test)]
+    fn distance_for_press(&self, sec: i64) -> i64 {
+        sec * (self.time - sec)
+    }
+
+    #[cfg(test)]
+    fn get_ways_to_win_faster(&self) -> i64 {
+        let mut start = 0;
+        while start < self.time {
+            if self.distance_for_press(start) > self.distance {
+                break;
+            }
+            start += 1;
+        }
+        let mut end = self.time + 1;
+        while end > 0 {
+            if self.distance_for_press(end) > self.distance {
+                break;
+            }
+            end -= 1;
+        }
+        // neither search stops on a win if the race can't be won
+        if start > end || self.distance_for_press(start) <= self.distance {
+            return 0;
+        }
+        end - start + 1
+    }
+}
+
+/// Both readings of the sheet: part A sees several races, part B sees one
+/// long race once the spaces between the digits are ignored
+pub struct RaceSheet {
+    races: Vec<Race>,
+    long_race: Race,
+}
+
+/// The numbers after `label` on the next line, and those numbers run together
+fn parse_row<'a>(
+    input: &str,
+    lines: &mut impl Iterator<Item = &'a str>,
+    label: &str,
+) -> Result<(Vec<i64>, i64), ParseError> {
+    let expected = || format!("\"{label}: ...\"");
+    let line = lines
+        .next()
+        .ok_or_else(|| ParseError::missing(input, input, expected()))?;
+    let values = labelled(input, line, label)?;
+    Ok((numbers(input, values)?, joined_number(input, values)?))
+}
+
+fn parse_sheet(input: &str) -> Result<RaceSheet, ParseError> {
+    let mut lines = input.lines();
+    let (times, long_time) = parse_row(input, &mut lines, "Time")?;
+    let (distances, long_distance) = parse_row(input, &mut lines, "Distance")?;
+
+    let races = std::iter::zip(times, distances)
+        .map(|(time, distance)| Race { time, distance })
+        .collect();
+
+    Ok(RaceSheet {
+        races,
+        long_race: Race {
+            time: long_time,
+            distance: long_distance,
+        },
+    })
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input = RaceSheet;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_sheet(input)
+    }
+
+    fn part_a(sheet: &Self::Input) -> Answer {
+        sheet
+            .races
+            .iter()
+            .map(|r| r.get_win_ways_count())
+            .reduce(|acc, c| acc * c)
+            .unwrap()
+            .into()
+    }
+
+    fn part_b(sheet: &Self::Input) -> Answer {
+        sheet.long_race.get_win_ways_count().into()
+    }
+}
+
+aoc_common::example_tests!();
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use aoc_common::differential::{check, Differential};
+    use aoc_common::known::PartAnswers;
+    use proptest::prelude::*;
+
+    use super::*;
+
+    fn sheet(races: &[(i64, i64)]) -> String {
+        let (times, records): (Vec<_>, Vec<_>) = races
+            .iter()
+            .map(|(time, record)| (time.to_string(), record.to_string()))
+            .unzip();
+        format!(
+            "Time: {}\nDistance: {}\n",
+            times.join(" "),
+            records.join(" ")
+        )
+    }
+
+    #[test]
+    fn test_faster_agrees() {
+        // up to the best distance a race allows, which can't be beaten
+        let race =
+            (0i64..100).prop_flat_map(|time| (Just(time), 0..=(time / 2) * (time - time / 2)));
+        let races = prop::collection::vec(race, 1..=3);
+        check(
+            Path::new(env!("CARGO_MANIFEST_DIR")),
+            races,
+            Differential {
+                input: |races: &Vec<_>| sheet(races),
+                trusted: |races: &Vec<_>| {
+                    let sheet = parse_sheet(&sheet(races)).unwrap();
+                    PartAnswers {
+                        a: Some(Puzzle::part_a(&sheet)),
+                        b: Some(Puzzle::part_b(&sheet)),
+                    }
+                },
+                tested: |races: &Vec<_>| {
+                    let sheet = parse_sheet(&sheet(races)).unwrap();
+                    let faster = sheet.races.iter().map(Race::get_ways_to_win_faster);
+                    PartAnswers {
+                        a: Some(faster.product::<i64>().into()),
+                        b: Some(sheet.long_race.get_ways_to_win_faster().into()),
+                    }
+                },
+            },
+        );
+    }
+}
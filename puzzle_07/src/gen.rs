@@ -0,0 +1,47 @@
+//! Random Camel Cards: `size` different hands, so no two ever tie, each bidding up to 1000.
+
+use std::fmt::Write;
+
+use aoc_common::gen::{distinct, IndexedRandom, Rng, RngExt};
+
+use crate::CARDS;
+
+pub fn generate<R: Rng>(rng: &mut R, size: usize) -> String {
+    let cards = CARDS.chars().collect::<Vec<_>>();
+    let hands = distinct(rng, size, |rng| {
+        // dealing from a few kinds of card at a time makes pairs and better common
+        let kinds = rng.random_range(1..=5);
+        let pool = cards.sample(rng, kinds).copied().collect::<Vec<_>>();
+        (0..5)
+            .map(|_| *pool.choose(rng).unwrap())
+            .collect::<String>()
+    });
+    let mut input = String::new();
+    for hand in hands {
+        let _ = writeln!(input, "{hand} {}", rng.random_range(1..=1000));
+    }
+    input
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use aoc_common::gen::seeded;
+    use aoc_common::Solution;
+
+    use super::*;
+    use crate::Puzzle;
+
+    #[test]
+    fn test_generate() {
+        for seed in 0..20 {
+            let input = generate(&mut seeded(seed), 50);
+            let hands = Puzzle::parse(&input).unwrap();
+            let different = hands.iter().map(|hand| &hand.cards).collect::<HashSet<_>>();
+            assert_eq!(different.len(), 50);
+            Puzzle::part_a(&hands);
+            Puzzle::part_b(&hands);
+        }
+    }
+}
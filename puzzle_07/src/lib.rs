@@ -0,0 +1,254 @@
+pub mod gen;
+
+use aoc_common::parse::number;
+use aoc_common::{Answer, ParseError, Solution};
+use counter::Counter;
+
+const CARDS: &str = "AKQJT98765432";
+
+fn strength(c: char) -> Option<i32> {
+    let idx = CARDS.find(c);
+    idx.map(|i| (CARDS.len() - i) as i32)
+}
+
+fn strength_part2(c: char) -> Option<i32> {
+    let cards: &str = "AKQT98765432J";
+    let idx = cards.find(c).expect("not a real card");
+    Some((cards.len() - idx) as i32)
+}
+
+#[derive(PartialEq, PartialOrd, Eq, Ord, Debug)]
+enum HandType {
+    HighCard = 1,
+    OnePair = 2,
+    TwoPair = 3,
+    ThreeOfAKind = 4,
+    FullHouse = 5,
+    FourOfAKind = 6,
+    FiveOfAKind = 7,
+}
+
+#[derive(PartialEq, Eq, PartialOrd, Debug, Clone, Copy)]
+enum Ruleset {
+    A,
+    B,
+}
+
+#[derive(Debug, Eq)]
+pub struct Hand {
+    cards: Vec<char>,
+    bid: i32,
+    ruleset: Ruleset,
+}
+
+impl Hand {
+    fn from_str(input: &str, line: &str, ruleset: Ruleset) -> Result<Self, ParseError> {
+        let mut toks = line.split_whitespace();
+        let cards_str = toks
+            .next()
+            .ok_or_else(|| ParseError::new(input, line, "a hand of five cards"))?;
+        let bid_str = toks
+            .next()
+            .ok_or_else(|| ParseError::missing(input, cards_str, "a bid"))?;
+        let bid = number(input, bid_str)?;
+        for (i, card) in cards_str.char_indices() {
+            if !CARDS.contains(card) {
+                let token = &cards_str[i..i + card.len_utf8()];
+                return Err(ParseError::new(input, token, format!("one of {CARDS}")));
+            }
+        }
+        let cards = cards_str.chars().collect::<Vec<char>>();
+        if cards.len() != 5 {
+            return Err(ParseError::new(input, cards_str, "exactly five cards"));
+        };
+        Ok(Self {
+            cards,
+            bid,
+            ruleset,
+        })
+    }
+
+    fn with_ruleset(&self, ruleset: Ruleset) -> Self {
+        Self {
+            cards: self.cards.clone(),
+            bid: self.bid,
+            ruleset,
+        }
+    }
+
+    fn compute_type(&self) -> HandType {
+        let mut counter = self.cards.iter().collect::<Counter<_>>();
+        let mut by_common = counter.most_common();
+        let mut j_count = *counter.get(&'J').unwrap_or(&0) as i32;
+
+        if self.ruleset == Ruleset::B {
+            while j_count > 0 {
+                // jokers join the most common real card, JJJJJ stays as it is
+                let Some(&(mostkey, _)) = by_common.iter().find(|(card, _)| **card != 'J') else {
+                    break;
+                };
+                *counter.get_mut(mostkey).unwrap() += 1;
+                *counter.get_mut(&'J').unwrap() -= 1;
+                j_count -= 1;
+                by_common = counter.most_common();
+            }
+        }
+
+        match by_common[0].1 {
+            5 => HandType::FiveOfAKind,
+            4 => HandType::FourOfAKind,
+            3 => match by_common[1].1 {
+                2 => HandType::FullHouse,
+                _ => HandType::ThreeOfAKind,
+            },
+            2 => match by_common[1].1 {
+                2 => HandType::TwoPair,
+                _ => HandType::OnePair,
+            },
+            _ => HandType::HighCard,
+        }
+    }
+
+    fn card_strengths(&self) -> Vec<i32> {
+        let strength_func = match self.ruleset {
+            Ruleset::A => strength,
+            Ruleset::B => strength_part2,
+        };
+        self.cards
+            .iter()
+            .map(|c| strength_func(*c).unwrap())
+            .collect::<Vec<_>>()
+    }
+
+    fn sort_key(&self) -> Vec<i32> {
+        let mut key = Vec::new();
+        key.push(self.compute_type() as i32);
+        let scores = self.card_strengths();
+        scores.iter().for_each(|v| key.push(*v));
+        key
+    }
+}
+
+impl PartialEq for Hand {
+    fn eq(&self, other: &Hand) -> bool {
+        self.compute_type() == other.compute_type() && self.cards[0] == other.cards[0]
+    }
+}
+
+impl std::cmp::PartialOrd for Hand {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Hand {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        let self_type = self.compute_type();
+        let other_type = other.compute_type();
+        if self_type == other_type {
+            self.card_strengths().cmp(&other.card_strengths())
+        } else {
+            self_type.cmp(&other_type)
+        }
+    }
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input = Vec<Hand>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input
+            .lines()
+            .map(|line| Hand::from_str(input, line, Ruleset::A))
+            .collect()
+    }
+
+    fn part_a(hands: &Self::Input) -> Answer {
+        let mut hands = hands.iter().collect::<Vec<_>>();
+        hands.sort();
+        let mut total = 0;
+        for (i, hand) in hands.iter().enumerate() {
+            total += hand.bid * (i as i32 + 1)
+        }
+        total.into()
+    }
+
+    fn part_b(hands: &Self::Input) -> Answer {
+        let mut hands = hands
+            .iter()
+            .map(|hand| hand.with_ruleset(Ruleset::B))
+            .collect::<Vec<_>>();
+        // hands.sort_unstable_by(|a, b| a.sort_key().cmp(&b.sort_key()));
+        hands.sort_by(|a, b| {
+            let key_a = a.sort_key();
+            let key_b = b.sort_key();
+            for i in 0..key_a.len() {
+                if key_a[i] != key_b[i] {
+                    return key_a[i].cmp(&key_b[i]);
+                }
+            }
+            std::cmp::Ordering::Equal
+        });
+        let mut total = 0;
+        for (i, hand) in hands.iter().enumerate() {
+            total += hand.bid * (i as i32 + 1)
+        }
+        total.into()
+    }
+}
+
+aoc_common::example_tests!();
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hand_type(cards: &str, ruleset: Ruleset) -> HandType {
+        let hand = Hand {
+            cards: cards.chars().collect(),
+            bid: 0,
+            ruleset,
+        };
+        hand.compute_type()
+    }
+
+    #[test]
+    fn test_jokers() {
+        // jokers join the most common other card, never each other
+        assert_eq!(hand_type("JJJ12", Ruleset::B), HandType::FourOfAKind);
+        assert_eq!(hand_type("2JJJJ", Ruleset::B), HandType::FiveOfAKind);
+        assert_eq!(hand_type("JJJJJ", Ruleset::B), HandType::FiveOfAKind);
+        assert_eq!(hand_type("JJJ12", Ruleset::A), HandType::ThreeOfAKind);
+    }
+
+    #[test]
+    fn test_card_strength() {
+        assert!(strength('A') > strength('K'));
+        assert!(strength('3') > strength('2'));
+    }
+
+    #[test]
+    fn test_card_strength2() {
+        assert!(strength_part2('K') > strength_part2('Q'));
+        assert!(strength_part2('Q') > strength_part2('T'));
+    }
+
+    #[test]
+    fn test_hand_strength() {
+        assert!(HandType::FullHouse > HandType::ThreeOfAKind);
+    }
+
+    #[test]
+    fn test_lexical_ordering() {
+        let a = vec![6, 12, 10, 1, 1, 10];
+        let b = vec![6, 11, 11, 11, 1, 13];
+        // let a = vec![6, 12];
+        // let b = vec![6, 11];
+        assert!(a > b);
+        let mut sortable = vec![&a, &b];
+        sortable.sort();
+        assert_eq!(sortable, vec![&b, &a]);
+    }
+}
@@ -0,0 +1,120 @@
+//! Random networks: a few ghosts, each walking its own loop of up to `size` steps, and
+//! up to `size` turns to follow.
+//!
+//! Every step of a loop may have two nodes to choose from, but the step holding the
+//! loop's one node ending in Z never does, so whichever way the turns go, each ghost
+//! keeps coming back to its Z. The Zs are placed so that the ghosts all stand on theirs
+//! at some shared step, and the ghost starting at AAA has ZZZ on its loop, so both parts
+//! have an answer.
+
+use std::collections::HashSet;
+use std::fmt::Write;
+
+use aoc_common::gen::{IndexedRandom, Rng, RngExt, SliceRandom};
+
+const LETTERS: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+pub fn generate<R: Rng>(rng: &mut R, size: usize) -> String {
+    let size = size.max(2);
+    let turns = (0..rng.random_range(1..=size))
+        .map(|_| if rng.random_bool(0.5) { 'L' } else { 'R' })
+        .collect::<String>();
+
+    let mut taken = HashSet::from(["AAA".to_string(), "ZZZ".to_string()]);
+    let mut fresh = |rng: &mut R, last: &[u8]| loop {
+        let name = [LETTERS.choose(rng), LETTERS.choose(rng), last.choose(rng)]
+            .map(|letter| *letter.unwrap() as char)
+            .iter()
+            .collect::<String>();
+        if taken.insert(name.clone()) {
+            return name;
+        }
+    };
+
+    // without a step every ghost is on its Z at, their loops might never line up
+    let meet = rng.random_range(1..=size * size);
+    let mut lines = Vec::new();
+    for ghost in 0..rng.random_range(1..=6) {
+        let (start, goal) = match ghost {
+            0 => ("AAA".to_string(), "ZZZ".to_string()),
+            _ => (fresh(rng, b"A"), fresh(rng, b"Z")),
+        };
+        let len = rng.random_range(2..=size);
+        // the start is one step before the loop
+        let goal_step = (meet - 1) % len;
+        let steps = (0..len)
+            .map(|step| match step == goal_step {
+                true => vec![goal.clone()],
+                false => {
+                    let nodes = rng.random_range(1..=2);
+                    (0..nodes).map(|_| fresh(rng, &LETTERS[1..25])).collect()
+                }
+            })
+            .collect::<Vec<Vec<_>>>();
+
+        let mut link = |name: &str, next: &[String]| {
+            let left = next.choose(rng).unwrap();
+            let right = next.choose(rng).unwrap();
+            lines.push(format!("{name} = ({left}, {right})"));
+        };
+        link(&start, &steps[0]);
+        for (step, nodes) in steps.iter().enumerate() {
+            for node in nodes {
+                link(node, &steps[(step + 1) % len]);
+            }
+        }
+    }
+    lines.shuffle(rng);
+
+    let mut input = format!("{turns}\n\n");
+    for line in lines {
+        let _ = writeln!(input, "{line}");
+    }
+    input
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::gen::seeded;
+    use aoc_common::{Answer, Solution};
+
+    use super::*;
+    use crate::{ParseResults, Puzzle, Turn};
+
+    /// Walk every ghost at once until they all stand on a Z
+    fn brute_force(network: &ParseResults) -> u64 {
+        let mut ghosts = network
+            .nodes
+            .keys()
+            .filter(|node| node.ends_with('A'))
+            .collect::<Vec<_>>();
+        let mut steps = 0;
+        while !ghosts.iter().all(|node| node.ends_with('Z')) {
+            let turn = network.turns[steps as usize % network.turns.len()];
+            for ghost in &mut ghosts {
+                let (left, right) = &network.nodes[*ghost];
+                *ghost = if turn == Turn::Left { left } else { right };
+            }
+            steps += 1;
+        }
+        steps
+    }
+
+    #[test]
+    fn test_generate() {
+        for seed in 0..50 {
+            let input = generate(&mut seeded(seed), 6);
+            let network = Puzzle::parse(&input).unwrap();
+            assert!(network.nodes.contains_key("AAA"));
+            for (left, right) in network.nodes.values() {
+                assert!(network.nodes.contains_key(left) && network.nodes.contains_key(right));
+            }
+            Puzzle::part_a(&network);
+            assert_eq!(
+                Puzzle::part_b(&network),
+                Answer::from(brute_force(&network)),
+                "{input}"
+            );
+        }
+    }
+}
@@ -0,0 +1,226 @@
+pub mod gen;
+
+use std::collections::BTreeMap;
+
+use aoc_common::math::crt;
+use aoc_common::parse::key_pair;
+use aoc_common::simulate::simulate;
+use aoc_common::{Answer, ParseError, Solution};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Turn {
+    Left,
+    Right,
+}
+
+#[derive(Debug)]
+pub struct ParseResults {
+    turns: Vec<Turn>,
+    nodes: BTreeMap<String, (String, String)>,
+}
+
+impl ParseResults {
+    fn from_str(input: &str) -> Result<Self, ParseError> {
+        let mut turns = Vec::new();
+        let mut nodes: BTreeMap<String, (String, String)> = BTreeMap::new();
+        let mut targets = Vec::new();
+
+        for mut line in input.lines() {
+            line = line.trim();
+            if line.is_empty() {
+                continue;
+            }
+            if turns.is_empty() {
+                turns = line
+                    .char_indices()
+                    .map(|(i, c)| match c {
+                        'L' => Ok(Turn::Left),
+                        'R' => Ok(Turn::Right),
+                        _ => Err(ParseError::new(input, &line[i..i + c.len_utf8()], "L or R")),
+                    })
+                    .collect::<Result<_, _>>()?;
+                continue;
+            }
+
+            let (key, left, right) = key_pair(input, line)?;
+            nodes.insert(key.to_owned(), (left.to_owned(), right.to_owned()));
+            targets.extend([left, right]);
+        }
+
+        if turns.is_empty() {
+            return Err(ParseError::missing(input, input, "a line of L and R turns"));
+        }
+        // so that walking the network never steps off it
+        if let Some(target) = targets.into_iter().find(|&t| !nodes.contains_key(t)) {
+            return Err(ParseError::new(
+                input,
+                target,
+                "a node defined on its own line",
+            ));
+        }
+        Ok(ParseResults { turns, nodes })
+    }
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input = ParseResults;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        ParseResults::from_str(input)
+    }
+
+    fn part_a(parsed: &Self::Input) -> Answer {
+        match count_steps_a(parsed) {
+            Some(count) => count.into(),
+            None => "no AAA node to start from".into(),
+        }
+    }
+
+    fn part_b(parsed: &Self::Input) -> Answer {
+        match count_steps_b(parsed) {
+            Some(count) => count.into(),
+            None => "the ghosts never all reach a Z together".into(),
+        }
+    }
+}
+
+fn count_steps_a(parsed: &ParseResults) -> Option<u64> {
+    let turns = &parsed.turns;
+    let mut count = 0;
+    let mut current_node = "AAA";
+    if !parsed.nodes.contains_key(current_node) {
+        return None;
+    }
+    while current_node != "ZZZ" {
+        // parsing checked that there are turns and that every node leads to another
+        let (l, r) = &parsed.nodes[current_node];
+        current_node = match turns[count % turns.len()] {
+            Turn::Left => l,
+            Turn::Right => r,
+        };
+        count += 1;
+    }
+    Some(count as u64)
+}
+
+/// The first step at which every ghost is on a Z, if there is one
+fn count_steps_b(parsed: &ParseResults) -> Option<i64> {
+    let turns = &parsed.turns;
+    let nodes = &parsed.nodes;
+    // A ghost is a node and how far through the turns it is, so it has to loop eventually
+    let walk = |&(node, turn): &(&str, usize)| {
+        let (l, r) = &nodes[node];
+        let next = match turns[turn] {
+            Turn::Left => l,
+            Turn::Right => r,
+        };
+        (next.as_str(), (turn + 1) % turns.len())
+    };
+    let ghosts = nodes
+        .keys()
+        .filter(|k| k.ends_with('A'))
+        .map(|start| simulate((start.as_str(), 0), &walk, usize::MAX))
+        .collect::<Vec<_>>();
+    let at_end = |(node, _): &(&str, usize)| node.ends_with('Z');
+
+    // Until every ghost has reached its loop, just look
+    let cycles = ghosts
+        .iter()
+        .map(|ghost| ghost.cycle())
+        .collect::<Option<Vec<_>>>()?;
+    let tail = cycles.iter().map(|cycle| cycle.start).max().unwrap_or(0);
+    if let Some(count) = (1..tail).find(|&t| ghosts.iter().all(|g| at_end(g.state_at(t)))) {
+        return Some(count as i64);
+    }
+
+    // After that each ghost is at a Z at fixed offsets into its loop
+    let mut solutions = vec![(0, 1)];
+    for (ghost, cycle) in ghosts.iter().zip(cycles) {
+        let hits = (cycle.start..cycle.start + cycle.len)
+            .filter(|&t| at_end(&ghost.history()[t]))
+            .map(|t| (t as i64, cycle.len as i64))
+            .collect::<Vec<_>>();
+        solutions = solutions
+            .iter()
+            .flat_map(|&solution| hits.iter().filter_map(move |&hit| crt(&[solution, hit])))
+            .collect();
+    }
+    solutions
+        .into_iter()
+        .map(|(count, modulus)| match count < tail as i64 {
+            true => count + (tail as i64 - count + modulus - 1) / modulus * modulus,
+            false => count,
+        })
+        .min()
+}
+
+aoc_common::example_tests!();
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_errors() {
+        let err = |input| Puzzle::parse(input).err().unwrap();
+        assert_eq!(err("\n").expected, "a line of L and R turns");
+        let undefined = err("LR\n\nAAA = (BBB, ZZZ)\nZZZ = (ZZZ, ZZZ)\n");
+        assert_eq!(undefined.token, "BBB");
+        assert_eq!((undefined.line, undefined.column), (3, 8));
+        assert_eq!(err("LX\n").token, "X");
+    }
+
+    #[test]
+    fn test_ghosts_out_of_step() {
+        // 11A is on a Z at every odd step from 3, 22A at 1, 4, 7..., so neither the
+        // first Zs nor the loop lengths give the answer
+        let network = Puzzle::parse(
+            "L
+
+11A = (11B, 11B)
+11B = (11C, 11C)
+11C = (11Z, 11Z)
+11Z = (11C, 11C)
+22A = (22Z, 22Z)
+22Z = (22B, 22B)
+22B = (22C, 22C)
+22C = (22Z, 22Z)
+",
+        )
+        .unwrap();
+        assert_eq!(Puzzle::part_b(&network), Answer::from(7));
+    }
+
+    #[test]
+    fn test_ghosts_never_meet() {
+        // 11A is on a Z at odd steps, 22A at even ones
+        let network = Puzzle::parse(
+            "L
+
+11A = (11Z, 11Z)
+11Z = (11B, 11B)
+11B = (11Z, 11Z)
+22A = (22B, 22B)
+22B = (22Z, 22Z)
+22Z = (22B, 22B)
+",
+        )
+        .unwrap();
+        assert_eq!(
+            Puzzle::part_b(&network),
+            Answer::from("the ghosts never all reach a Z together")
+        );
+    }
+
+    #[test]
+    fn test_no_start() {
+        let network = Puzzle::parse("L\n\n11A = (11Z, 11Z)\n11Z = (11A, 11A)\n").unwrap();
+        assert_eq!(
+            Puzzle::part_a(&network),
+            Answer::from("no AAA node to start from")
+        );
+        assert_eq!(Puzzle::part_b(&network), Answer::from(1));
+    }
+}
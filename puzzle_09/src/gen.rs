@@ -0,0 +1,47 @@
+//! Random OASIS reports: `size` histories, each a polynomial of degree up to 5 read at
+//! 7 to 21 points, so the differences always reach zero.
+
+use std::fmt::Write;
+
+use aoc_common::gen::{Rng, RngExt};
+
+pub fn generate<R: Rng>(rng: &mut R, size: usize) -> String {
+    let mut input = String::new();
+    for _ in 0..size {
+        let len = rng.random_range(7..=21);
+        let degree = rng.random_range(0..=5);
+        // the first value at each level of differences, down to the constant one
+        let mut firsts = (0..=degree)
+            .map(|_| rng.random_range(-20..=20))
+            .collect::<Vec<i64>>();
+        let mut values = Vec::with_capacity(len);
+        for _ in 0..len {
+            values.push(firsts[0].to_string());
+            for level in 0..degree {
+                firsts[level] += firsts[level + 1];
+            }
+        }
+        let _ = writeln!(input, "{}", values.join(" "));
+    }
+    input
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::gen::seeded;
+    use aoc_common::Solution;
+
+    use super::*;
+    use crate::Puzzle;
+
+    #[test]
+    fn test_generate() {
+        for seed in 0..20 {
+            let input = generate(&mut seeded(seed), 10);
+            let seqs = Puzzle::parse(&input).unwrap();
+            assert_eq!(seqs.len(), 10);
+            Puzzle::part_a(&seqs);
+            Puzzle::part_b(&seqs);
+        }
+    }
+}
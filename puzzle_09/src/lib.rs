@@ -0,0 +1,109 @@
+pub mod gen;
+
+use std::collections::VecDeque;
+
+use aoc_common::parse::number;
+use aoc_common::{Answer, ParseError, Solution};
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input = Vec<Sequence>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input
+            .lines()
+            .map(|line| {
+                let mut seq = Sequence::init(input, line)?;
+                seq.gen_deltas();
+                Ok(seq)
+            })
+            .collect()
+    }
+
+    fn part_a(seqs: &Self::Input) -> Answer {
+        let mut results = Vec::new();
+        for seq in seqs {
+            results.push(seq.clone().extrapolate());
+        }
+        results.iter().fold(0, |acc, val| acc + *val).into()
+    }
+
+    fn part_b(seqs: &Self::Input) -> Answer {
+        let mut results = Vec::new();
+        for seq in seqs {
+            results.push(seq.clone().extrapolate_front());
+        }
+        results.iter().fold(0, |acc, val| acc + *val).into()
+    }
+}
+
+#[derive(Clone)]
+pub struct Sequence {
+    vecs: Vec<VecDeque<i32>>,
+}
+
+impl Sequence {
+    fn init(input: &str, line: &str) -> Result<Self, ParseError> {
+        let seq = line
+            .split_whitespace()
+            .map(|tok| number(input, tok))
+            .collect::<Result<VecDeque<i32>, _>>()?;
+        if seq.is_empty() {
+            return Err(ParseError::new(input, line, "a sequence of numbers"));
+        }
+        Ok(Self { vecs: vec![seq] })
+    }
+
+    fn gen_deltas(&mut self) {
+        let mut vec_idx = 0;
+        loop {
+            let current_vec = self.vecs.get(vec_idx).unwrap();
+            let mut deltas = VecDeque::<i32>::new();
+            for i in 1..current_vec.len() {
+                deltas.push_back(current_vec[i] - current_vec[i - 1]);
+            }
+            assert!(deltas.len() == current_vec.len() - 1);
+            self.vecs.push(deltas);
+            vec_idx += 1;
+            if self.vecs[vec_idx].iter().all(|val| *val == 0) {
+                break;
+            }
+        }
+        // println!("Stopped looping after computing {} delta series", vec_idx);
+    }
+
+    fn extrapolate(&mut self) -> i32 {
+        // let zeros = &mut self.vecs.last().unwrap();
+        // assert!(zeros.iter().all(|v| *v == 0));
+        // zeros.push(0);
+        let n_vecs = self.vecs.len();
+        self.vecs[n_vecs - 1].push_back(0);
+        for vec_idx in (n_vecs - 2)..0 {
+            let last_delta = *self.vecs[vec_idx + 1].back().unwrap();
+            let this_vec = &mut self.vecs[vec_idx];
+            this_vec.push_back(this_vec.back().unwrap() + last_delta);
+        }
+
+        let sum = self
+            .vecs
+            .iter()
+            .fold(0, |acc, val| acc + val.back().unwrap());
+        sum
+    }
+
+    fn extrapolate_front(&mut self) -> i32 {
+        let n_vecs = self.vecs.len();
+        self.vecs[n_vecs - 1].push_front(0);
+        for vec_idx in (0..(n_vecs - 2)).rev() {
+            let last_delta = *self.vecs[vec_idx + 1].front().unwrap();
+            let this_vec = &mut self.vecs[vec_idx];
+            this_vec.push_front(this_vec.front().unwrap() - last_delta);
+            // print!("{:?}", this_vec);
+        }
+
+        *self.vecs[0].front().unwrap()
+    }
+}
+
+aoc_common::example_tests!();
@@ -0,0 +1,182 @@
+//! Random pipe mazes: a `size` by `size` field of junk pipes with exactly one loop
+//! through S.
+//!
+//! The loop is the outline of a random blob of squares, with the pipes running along
+//! the squares' corners. Growing the blob only where it touches itself along one
+//! unbroken stretch keeps it free of holes and of squares meeting at just a corner, so
+//! its outline never crosses or touches itself.
+
+use aoc_common::gen::{IndexedRandom, Rng, RngExt};
+use aoc_grid::{Coord, Dir4, Direction, Grid};
+
+use super::dirs_for_char;
+
+/// The squares around a square, in order around it
+const RING: [(isize, isize); 8] = [
+    (-1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+    (1, 0),
+    (1, -1),
+    (0, -1),
+    (-1, -1),
+];
+
+pub fn generate<R: Rng>(rng: &mut R, size: usize) -> String {
+    let blob = blob(rng, size.max(2) - 1);
+    draw(rng, &blob)
+}
+
+/// A random blob of squares that is one piece, without holes, on a `side` by `side` board
+fn blob<R: Rng>(rng: &mut R, side: usize) -> Grid<bool> {
+    let first = Coord::new(rng.random_range(0..side), rng.random_range(0..side));
+    let target = rng.random_range(1..=side * side / 2 + 1);
+    let moves = (0..target * 20)
+        .map(|_| (rng.random_range(0..target), *Dir4::ALL.choose(rng).unwrap()))
+        .collect::<Vec<_>>();
+    grow(side, first, target, moves)
+}
+
+/// Grow a blob from the square at `first` until it has `target` squares or runs out of
+/// moves. A move `(i, dir)` adds the square past the `i`th one added (wrapping around)
+/// in direction `dir`, if that keeps the blob in one piece without holes.
+pub(crate) fn grow(
+    side: usize,
+    first: Coord,
+    target: usize,
+    moves: impl IntoIterator<Item = (usize, Dir4)>,
+) -> Grid<bool> {
+    let mut blob = Grid::new(side, side, false);
+    blob[first] = true;
+    let mut squares = vec![first];
+    for (i, dir) in moves {
+        if squares.len() >= target {
+            break;
+        }
+        let Some(next) = blob.step(squares[i % squares.len()], dir) else {
+            continue;
+        };
+        if !blob[next] && touches_once(&blob, next) {
+            blob[next] = true;
+            squares.push(next);
+        }
+    }
+    blob
+}
+
+/// The answers for the loop around `blob`: the farthest point along it, and the tiles
+/// it encloses, which are the corners with blob on every side
+#[cfg(test)]
+pub(crate) fn answers(blob: &Grid<bool>) -> (usize, usize) {
+    let corners =
+        (1..blob.height()).flat_map(|row| (1..blob.width()).map(move |col| Coord::new(row, col)));
+    let enclosed = corners
+        .filter(|corner| {
+            [(-1, -1), (-1, 0), (0, -1), (0, 0)]
+                .iter()
+                .all(|&(drow, dcol)| blob[corner.offset(drow, dcol).unwrap()])
+        })
+        .count();
+    let squares = blob.iter().filter(|(_, inside)| **inside).count();
+    // Pick's theorem, with the blob's area as the loop's
+    let loop_len = 2 * (squares - enclosed) + 2;
+    (loop_len / 2, enclosed)
+}
+
+/// Whether the blob's squares around `square` make a single unbroken stretch that
+/// shares a side with it
+fn touches_once(blob: &Grid<bool>, square: Coord) -> bool {
+    let inside = RING.map(|(drow, dcol)| {
+        square
+            .offset(drow, dcol)
+            .and_then(|coord| blob.get(coord).copied())
+            .unwrap_or(false)
+    });
+    let starts = (0..RING.len())
+        .filter(|&i| inside[i] && !inside[(i + RING.len() - 1) % RING.len()])
+        .count();
+    let shares_side = (0..RING.len()).step_by(2).any(|i| inside[i]);
+    starts == 1 && shares_side
+}
+
+/// The blob's outline as pipes on its corners, S somewhere on it, and junk around it
+pub(crate) fn draw<R: Rng>(rng: &mut R, blob: &Grid<bool>) -> String {
+    let side = blob.width() + 1;
+    let mut links = Grid::new(side, side, Vec::new());
+    let mut link = |corner: Coord, a: Dir4, b: Dir4| {
+        links[corner].push(a);
+        let (drow, dcol) = a.delta();
+        let other = corner.offset(drow, dcol).unwrap();
+        links[other].push(b);
+    };
+    for (square, _) in blob.iter().filter(|(_, inside)| **inside) {
+        let Coord { row, col } = square;
+        let outside = |dir: Dir4| !blob.step(square, dir).is_some_and(|next| blob[next]);
+        if outside(Dir4::North) {
+            link(Coord::new(row, col), Dir4::East, Dir4::West);
+        }
+        if outside(Dir4::South) {
+            link(Coord::new(row + 1, col), Dir4::East, Dir4::West);
+        }
+        if outside(Dir4::West) {
+            link(Coord::new(row, col), Dir4::South, Dir4::North);
+        }
+        if outside(Dir4::East) {
+            link(Coord::new(row, col + 1), Dir4::South, Dir4::North);
+        }
+    }
+
+    let pipes = ['|', '-', 'L', 'J', '7', 'F'];
+    let mut cells = links.map(|dirs| match dirs[..] {
+        [a, b] => *pipes
+            .iter()
+            .find(|pipe| {
+                dirs_for_char(pipe).is_some_and(|ends| ends.contains(&a) && ends.contains(&b))
+            })
+            .unwrap(),
+        [] if rng.random_bool(0.4) => '.',
+        [] => *pipes.choose(rng).unwrap(),
+        _ => unreachable!("the outline crosses itself at {dirs:?}"),
+    });
+    let on_loop = links
+        .iter()
+        .filter(|(_, dirs)| !dirs.is_empty())
+        .map(|(corner, _)| corner)
+        .collect::<Vec<_>>();
+    let start = *on_loop.choose(rng).unwrap();
+    cells[start] = 'S';
+    // junk next to S mustn't look like it joins up with it
+    for dir in Dir4::iter() {
+        if let Some(next) = cells.step(start, dir) {
+            let joins =
+                dirs_for_char(&cells[next]).is_some_and(|ends| ends.contains(&dir.invert()));
+            if joins && links[next].is_empty() {
+                cells[next] = '.';
+            }
+        }
+    }
+    cells.to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::gen::seeded;
+    use aoc_common::{Answer, Solution};
+
+    use super::*;
+    use crate::Puzzle;
+
+    #[test]
+    fn test_generate() {
+        for seed in 0..50 {
+            let mut rng = seeded(seed);
+            let blob = blob(&mut rng, 11);
+            let input = draw(&mut rng, &blob);
+            let grid = Puzzle::parse(&input).unwrap();
+            let (farthest, enclosed) = answers(&blob);
+            assert_eq!(Puzzle::part_a(&grid), Answer::from(farthest), "{input}");
+            assert_eq!(Puzzle::part_b(&grid), Answer::from(enclosed), "{input}");
+        }
+    }
+}
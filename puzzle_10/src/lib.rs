@@ -0,0 +1,342 @@
+pub mod gen;
+
+use std::collections::HashSet;
+
+use aoc_common::{Answer, ParseError, Solution};
+use aoc_grid::frames::{self, Frame};
+use aoc_grid::{Coord, Dir4, Grid};
+
+use Dir4::*;
+
+fn dirs_for_char(c: &char) -> Option<[Dir4; 2]> {
+    match c {
+        '|' => Some([North, South]),
+        '-' => Some([West, East]),
+        'L' => Some([North, East]),
+        'J' => Some([North, West]),
+        '7' => Some([West, South]),
+        'F' => Some([South, East]),
+        _ => None,
+    }
+}
+
+pub struct PipeGrid {
+    cells: Grid<char>,
+    start_pos: Coord,
+}
+
+impl PipeGrid {
+    fn from_input(input: &str) -> Result<Self, ParseError> {
+        let cells = Grid::parse(
+            input,
+            |c| (c == 'S' || c == '.' || dirs_for_char(&c).is_some()).then_some(c),
+            "a pipe, . or S",
+        )?;
+        let starts = cells
+            .iter()
+            .filter(|(_, c)| **c == 'S')
+            .map(|(pos, _)| pos)
+            .collect::<Vec<_>>();
+        let start_pos = *starts
+            .first()
+            .ok_or_else(|| ParseError::missing(input, input, "a start tile S"))?;
+        if let Some(&other) = starts.get(1) {
+            return Err(ParseError::new(
+                input,
+                tile(input, other),
+                "only one start tile S",
+            ));
+        }
+        let grid = Self { cells, start_pos };
+        grid.check_loop(input)?;
+        Ok(grid)
+    }
+
+    fn char_at(&self, pos: &Coord) -> char {
+        self.cells[*pos]
+    }
+
+    /// Whether the tile a step `dir` away from `pos` is a pipe leading back to `pos`
+    fn joins(&self, pos: Coord, dir: Dir4) -> bool {
+        self.cells
+            .step(pos, dir)
+            .and_then(|next| dirs_for_char(&self.char_at(&next)))
+            .is_some_and(|dirs| dirs.contains(&dir.invert()))
+    }
+
+    /// Check that S is joined to exactly two pipes, and that following them leads
+    /// back around to S, so that the traversal always finds its way
+    fn check_loop(&self, input: &str) -> Result<(), ParseError> {
+        let joined = Dir4::ALL
+            .into_iter()
+            .filter(|&dir| self.joins(self.start_pos, dir))
+            .collect::<Vec<_>>();
+        if joined.len() != 2 {
+            return Err(ParseError::new(
+                input,
+                tile(input, self.start_pos),
+                "an S joined to exactly two pipes",
+            ));
+        }
+        let (mut pos, mut dir) = (self.start_pos, joined[0]);
+        loop {
+            let next = match self.cells.step(pos, dir) {
+                Some(next) if self.joins(pos, dir) || self.char_at(&next) == 'S' => next,
+                _ => {
+                    return Err(ParseError::new(
+                        input,
+                        tile(input, pos),
+                        "a pipe leading on to the next one in the loop",
+                    ))
+                }
+            };
+            match dirs_for_char(&self.char_at(&next)) {
+                Some([a, b]) => dir = if a == dir.invert() { b } else { a },
+                None => return Ok(()),
+            }
+            pos = next;
+        }
+    }
+}
+
+/// The text of the tile at `pos`, which is one byte as the grid only holds pipes, . and S
+fn tile(input: &str, pos: Coord) -> &str {
+    let line = input
+        .lines()
+        .nth(pos.row)
+        .expect("the grid was parsed from input");
+    &line[pos.col..=pos.col]
+}
+
+struct Traversal<'a> {
+    grid: &'a PipeGrid,
+    start_point: Coord,
+    cursor: Coord,
+    came_from: Option<Dir4>,
+    path: Vec<Coord>,
+}
+
+impl<'a> Traversal<'a> {
+    fn for_grid(grid: &'a PipeGrid) -> Self {
+        Self {
+            start_point: grid.start_pos,
+            cursor: grid.start_pos,
+            came_from: None,
+            path: vec![grid.start_pos],
+            grid,
+        }
+    }
+
+    fn step(&self, dir: &Dir4) -> Option<Coord> {
+        self.grid.cells.step(self.cursor, *dir)
+    }
+
+    fn scan_next_step(&self) -> Dir4 {
+        let dirs: Vec<Dir4> = match dirs_for_char(&self.grid.char_at(&self.cursor)) {
+            Some(dirs) => dirs.to_vec(),
+            None => Dir4::ALL.to_vec(),
+        };
+
+        for dir in dirs {
+            match self.step(&dir) {
+                None => continue,
+                Some(there) => {
+                    let entries = dirs_for_char(&self.grid.char_at(&there));
+                    match entries {
+                        None => match self.grid.char_at(&there) {
+                            'S' if self.came_from != Some(dir) => return dir,
+                            _ => continue,
+                        },
+                        Some(entries) => {
+                            if self.came_from == Some(dir) {
+                                continue;
+                            }
+
+                            if entries.iter().any(|d| *d == dir.invert()) {
+                                return dir;
+                            }
+                        }
+                    }
+                }
+            };
+        }
+        unreachable!("PipeGrid::from_input checks that the loop from S closes")
+    }
+
+    fn traverse(&mut self) {
+        loop {
+            let next_move = self.scan_next_step();
+            let next_pos = self.step(&next_move).unwrap();
+            self.cursor = next_pos;
+            self.path.push(next_pos);
+            self.came_from = Some(next_move.invert());
+            frames::emit(|| {
+                Frame::new(&self.grid.cells)
+                    .highlight(self.path.iter().copied())
+                    .caption(format!(
+                        "{}: {:?} to {}",
+                        self.path.len() - 1,
+                        next_move,
+                        self.grid.char_at(&next_pos)
+                    ))
+            });
+            if self.cursor == self.start_point {
+                break;
+            }
+        }
+    }
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input = PipeGrid;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        PipeGrid::from_input(input)
+    }
+
+    fn part_a(grid: &Self::Input) -> Answer {
+        let mut traversal = Traversal::for_grid(grid);
+        traversal.traverse();
+        let path_len = traversal.path.len();
+        (path_len / 2).into()
+    }
+
+    fn part_b(grid: &Self::Input) -> Answer {
+        count_enclosed(grid).into()
+    }
+}
+
+fn count_enclosed(grid: &PipeGrid) -> i32 {
+    let mut traversal = Traversal::for_grid(grid);
+    traversal.traverse();
+    let path_len = traversal.path.len();
+
+    // What follows is a combination of
+    // The shoelace formula: https://en.wikipedia.org/wiki/Shoelace_formula
+    // and
+    // Pick's Theorem: https://en.wikipedia.org/wiki/Pick%27s_theorem
+    let mut sum: i32 = 0;
+    for i in 0..path_len - 1 {
+        let this = traversal.path.get(i).unwrap();
+        let next = traversal.path.get(i + 1).unwrap();
+        sum += (this.row as i32 + next.row as i32) * (this.col as i32 - next.col as i32);
+    }
+    let area = (sum / 2).abs();
+    let enclosed = area + 1 - (path_len as i32 / 2);
+
+    frames::emit(|| {
+        Frame::new(&grid.cells)
+            .highlight(traversal.path.iter().copied())
+            .mark(enclosed_tiles(grid, &traversal.path))
+            .caption(format!("{enclosed} tiles enclosed"))
+    });
+    enclosed
+}
+
+/// The tiles inside the loop, found by crossing it along each row. Only for
+/// drawing: the count above doesn't need them.
+fn enclosed_tiles(grid: &PipeGrid, path: &[Coord]) -> Vec<Coord> {
+    let on_path = path.iter().copied().collect::<HashSet<_>>();
+    // S stands in for whatever pipe joins the tiles either side of it on the path
+    let start_goes_north = [path[1], path[path.len() - 2]]
+        .iter()
+        .any(|&next| grid.cells.step(grid.start_pos, North) == Some(next));
+    let goes_north = |coord: Coord| match grid.char_at(&coord) {
+        'S' => start_goes_north,
+        c => dirs_for_char(&c).is_some_and(|dirs| dirs.contains(&North)),
+    };
+
+    let mut tiles = Vec::new();
+    for row in 0..grid.cells.height() {
+        let mut inside = false;
+        for col in 0..grid.cells.width() {
+            let coord = Coord::new(row, col);
+            if on_path.contains(&coord) {
+                if goes_north(coord) {
+                    inside = !inside;
+                }
+            } else if inside {
+                tiles.push(coord);
+            }
+        }
+    }
+    tiles
+}
+
+aoc_common::example_tests!();
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use aoc_common::differential::{check, Differential};
+    use aoc_common::gen::seeded;
+    use aoc_common::known::PartAnswers;
+    use aoc_common::Part;
+    use proptest::prelude::*;
+
+    use super::*;
+    use crate::gen::{answers, draw, grow};
+
+    /// The side of the board, the first square, the moves growing the blob from it, and a
+    /// seed for the junk around the loop
+    type Maze = (usize, usize, usize, Vec<(usize, usize)>, u64);
+
+    fn blob(&(side, row, col, ref moves, _): &Maze) -> Grid<bool> {
+        let moves = moves.iter().map(|&(i, dir)| (i, Dir4::ALL[dir]));
+        grow(side, Coord::new(row, col), usize::MAX, moves)
+    }
+
+    fn input(maze: &Maze) -> String {
+        draw(&mut seeded(maze.4), &blob(maze))
+    }
+
+    #[test]
+    fn test_broken_loops() {
+        let err = |input| Puzzle::parse(input).err().unwrap();
+        let dead_end = err("S-7\n|.|\nL-.\n");
+        assert_eq!((dead_end.line, dead_end.column), (2, 3));
+        assert_eq!(dead_end.token, "|");
+        let off_grid = err("S-\nL-\n");
+        assert_eq!((off_grid.line, off_grid.column), (1, 2));
+        assert_eq!(
+            err("S-.\n...\n").expected,
+            "an S joined to exactly two pipes"
+        );
+        // joined to F, - and L
+        assert_eq!(err("F-7\nS-J\nL-J\n").token, "S");
+        let second = err("S7\nLS\n");
+        assert_eq!((second.line, second.column), (2, 2));
+    }
+
+    #[test]
+    fn test_pick_agrees() {
+        let mazes = (2usize..9).prop_flat_map(|side| {
+            let moves = prop::collection::vec((any::<usize>(), 0..4usize), 0..60);
+            (Just(side), 0..side, 0..side, moves, any::<u64>())
+        });
+        check(
+            Path::new(env!("CARGO_MANIFEST_DIR")),
+            mazes,
+            Differential {
+                input,
+                trusted: |maze: &Maze| {
+                    let (farthest, enclosed) = answers(&blob(maze));
+                    PartAnswers {
+                        a: Some(farthest.into()),
+                        b: Some(enclosed.into()),
+                    }
+                },
+                tested: |maze: &Maze| {
+                    let input = input(maze);
+                    PartAnswers {
+                        a: Puzzle::solve(&input, Part::A).ok(),
+                        b: Puzzle::solve(&input, Part::B).ok(),
+                    }
+                },
+            },
+        );
+    }
+}
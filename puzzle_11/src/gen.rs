@@ -0,0 +1,38 @@
+//! Random images: a `size` by `size` sky where about one cell in twenty is a galaxy, with
+//! some rows and columns left empty to expand.
+
+use aoc_common::gen::{Rng, RngExt};
+
+pub fn generate<R: Rng>(rng: &mut R, size: usize) -> String {
+    let empty_rows = (0..size).map(|_| rng.random_bool(0.1)).collect::<Vec<_>>();
+    let empty_cols = (0..size).map(|_| rng.random_bool(0.1)).collect::<Vec<_>>();
+    let mut input = String::new();
+    for empty_row in &empty_rows {
+        for empty_col in &empty_cols {
+            let galaxy = !empty_row && !empty_col && rng.random_bool(0.05);
+            input.push(if galaxy { '#' } else { '.' });
+        }
+        input.push('\n');
+    }
+    input
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::gen::seeded;
+    use aoc_common::Solution;
+
+    use super::*;
+    use crate::Puzzle;
+
+    #[test]
+    fn test_generate() {
+        for seed in 0..20 {
+            let input = generate(&mut seeded(seed), 40);
+            let image = Puzzle::parse(&input).unwrap();
+            assert_eq!((image.size.rows, image.size.cols), (40, 40));
+            Puzzle::part_a(&image);
+            Puzzle::part_b(&image);
+        }
+    }
+}
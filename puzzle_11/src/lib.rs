@@ -0,0 +1,183 @@
+pub mod gen;
+
+use aoc_common::{Answer, ParseError, Solution};
+use aoc_grid::frames::{self, Frame};
+use aoc_grid::{Coord, Grid};
+use itertools::Itertools;
+
+#[derive(Debug, Clone, Copy)]
+pub struct Galaxy {
+    id: usize,
+    position: Coord,
+}
+
+impl Galaxy {
+    fn move_right(&mut self, amount: usize) {
+        self.position.col += amount;
+    }
+
+    fn move_down(&mut self, amount: usize) {
+        self.position.row += amount;
+    }
+}
+
+struct Size {
+    rows: usize,
+    cols: usize,
+}
+
+pub struct Image {
+    galaxies: Vec<Galaxy>,
+    size: Size,
+}
+
+fn parse_input(input: &str) -> Result<Image, ParseError> {
+    let image = Grid::parse(
+        input,
+        |c| match c {
+            '#' => Some(true),
+            '.' => Some(false),
+            _ => None,
+        },
+        "# or .",
+    )?;
+    let galaxies = image
+        .iter()
+        .filter(|(_, galaxy)| **galaxy)
+        .map(|(position, _)| position)
+        .enumerate()
+        .map(|(i, position)| Galaxy {
+            id: i + 1,
+            position,
+        })
+        .collect();
+    let size = Size {
+        rows: image.height(),
+        cols: image.width(),
+    };
+    Ok(Image { galaxies, size })
+}
+
+fn expand_galaxies(galaxies: &[Galaxy], size: &Size, amount: usize) -> Vec<Galaxy> {
+    let mut emptyrows = vec![true; size.rows];
+    let mut emptycols = vec![true; size.cols];
+
+    let mut expanded_galaxies = Vec::new();
+
+    for gxy in galaxies {
+        emptycols[gxy.position.col] = false;
+        emptyrows[gxy.position.row] = false;
+        expanded_galaxies.push(*gxy)
+    }
+
+    let mut col: usize = emptycols.len();
+    while col > 0 {
+        col -= 1;
+        match emptycols[col] {
+            false => (),
+            true => {
+                for (i, original_gxy) in &mut galaxies.iter().enumerate() {
+                    if original_gxy.position.col > col {
+                        let ng = expanded_galaxies.get_mut(i).unwrap();
+                        assert_eq!(original_gxy.id, ng.id);
+                        ng.move_right(amount);
+                    }
+                }
+            }
+        }
+    }
+
+    let mut row: usize = emptyrows.len();
+    while row > 0 {
+        row -= 1;
+        match emptyrows[row] {
+            false => (),
+            true => {
+                for (i, gal) in galaxies.iter().enumerate() {
+                    if gal.position.row > row {
+                        let ng = expanded_galaxies.get_mut(i).unwrap();
+                        ng.move_down(amount);
+                    }
+                }
+            }
+        }
+    }
+    expanded_galaxies
+}
+
+fn compute_distance(galaxies: &[Galaxy]) -> usize {
+    let mut total = 0;
+    for combo in galaxies.iter().combinations(2) {
+        let a = combo[0];
+        let b = combo[1];
+        let distance =
+            a.position.row.abs_diff(b.position.row) + a.position.col.abs_diff(b.position.col);
+        total += distance;
+    }
+    total
+}
+
+///////////
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input = Image;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_a(image: &Self::Input) -> Answer {
+        process_n(image, 2).into()
+    }
+
+    fn part_b(image: &Self::Input) -> Answer {
+        process_n(image, 1000000).into()
+    }
+}
+
+fn process_n(image: &Image, n: usize) -> usize {
+    let expanded = expand_galaxies(&image.galaxies, &image.size, n - 1);
+    let rows = expanded
+        .iter()
+        .map(|g| g.position.row + 1)
+        .max()
+        .unwrap_or(0);
+    let cols = expanded
+        .iter()
+        .map(|g| g.position.col + 1)
+        .max()
+        .unwrap_or(0);
+    // a million-fold expansion is too big to draw
+    if rows.max(cols) <= 2000 {
+        frames::emit(|| {
+            let mut sky = Grid::new(cols, rows, '.');
+            for galaxy in &expanded {
+                sky[galaxy.position] = '#';
+            }
+            Frame::new(&sky)
+                .highlight(expanded.iter().map(|g| g.position))
+                .caption(format!("expanded {n} times"))
+        });
+    }
+    compute_distance(&expanded)
+}
+
+aoc_common::example_tests!();
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    static INPUT: &str = include_str!("../examples/01.txt");
+
+    #[test]
+    fn test_b() {
+        let image = Puzzle::parse(INPUT).unwrap();
+        assert_eq!(process_n(&image, 10), 1030);
+        assert_eq!(process_n(&image, 100), 8410);
+        // let expected_output = 8410;
+        // assert_eq!(process_b(input), expected_output);
+    }
+}
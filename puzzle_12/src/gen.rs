@@ -0,0 +1,90 @@
+//! Random condition records: `size` rows of up to 16 springs, each made by laying out
+//! some damaged groups and then hiding springs behind `?`.
+//!
+//! Rows are kept short so that the unfolded counts of part B fit in a u64.
+
+use std::fmt::Write;
+
+use aoc_common::gen::{Rng, RngExt};
+
+pub fn generate<R: Rng>(rng: &mut R, size: usize) -> String {
+    let mut input = String::new();
+    for _ in 0..size {
+        let len = rng.random_range(1..=16);
+        let mut springs = (0..len)
+            .map(|_| if rng.random_bool(0.4) { '#' } else { '.' })
+            .collect::<Vec<_>>();
+        if !springs.contains(&'#') {
+            springs[rng.random_range(0..len)] = '#';
+        }
+        let groups = springs
+            .split(|&c| c == '.')
+            .filter(|group| !group.is_empty())
+            .map(|group| group.len().to_string())
+            .collect::<Vec<_>>();
+        let hidden = rng.random_range(0.2..0.8);
+        for spring in springs.iter_mut() {
+            if rng.random_bool(hidden) {
+                *spring = '?';
+            }
+        }
+        let springs = springs.into_iter().collect::<String>();
+        let _ = writeln!(input, "{springs} {}", groups.join(","));
+    }
+    input
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::gen::seeded;
+    use aoc_common::Solution;
+
+    use super::*;
+    use crate::Puzzle;
+
+    /// Every way to fill in the `?`s, kept if its groups match
+    fn brute_force(line: &str) -> u64 {
+        let (springs, groups) = line.split_once(' ').unwrap();
+        let unknown = springs.matches('?').count();
+        (0..1u32 << unknown)
+            .filter(|fill| {
+                let mut bit = 0;
+                let filled = springs
+                    .chars()
+                    .map(|c| match c {
+                        '?' => {
+                            bit += 1;
+                            if fill >> (bit - 1) & 1 == 1 {
+                                '#'
+                            } else {
+                                '.'
+                            }
+                        }
+                        c => c,
+                    })
+                    .collect::<String>();
+                let found = filled
+                    .split('.')
+                    .filter(|group| !group.is_empty())
+                    .map(|group| group.len().to_string())
+                    .collect::<Vec<_>>();
+                found.join(",") == groups
+            })
+            .count() as u64
+    }
+
+    #[test]
+    fn test_generate() {
+        for seed in 0..20 {
+            let input = generate(&mut seeded(seed), 20);
+            let records = Puzzle::parse(&input).unwrap();
+            assert_eq!(records.len(), 20);
+            for (line, record) in input.lines().zip(&records) {
+                let count = record.arrangements();
+                assert!(count >= 1, "{line} has no arrangement");
+                assert_eq!(count, brute_force(line), "{line}");
+            }
+            Puzzle::part_b(&records);
+        }
+    }
+}
@@ -0,0 +1,281 @@
+pub mod gen;
+
+use std::fmt::Write;
+
+use aoc_common::parse::number;
+use aoc_common::{Answer, ParseError, Part, Solution};
+
+/// How many arrangements `--explain` lists for a record before only counting them
+const MAX_LISTED: u64 = 10;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Spring {
+    Operational,
+    Damaged,
+    Unknown,
+}
+
+impl Spring {
+    fn from_char(c: char) -> Option<Self> {
+        match c {
+            '.' => Some(Spring::Operational),
+            '#' => Some(Spring::Damaged),
+            '?' => Some(Spring::Unknown),
+            _ => None,
+        }
+    }
+
+    fn to_char(self) -> char {
+        match self {
+            Spring::Operational => '.',
+            Spring::Damaged => '#',
+            Spring::Unknown => '?',
+        }
+    }
+}
+
+/// One row of the condition records: the springs, and the sizes of the runs of
+/// damaged ones in order
+#[derive(Debug, Clone)]
+pub struct Record {
+    springs: Vec<Spring>,
+    groups: Vec<usize>,
+}
+
+impl Record {
+    fn parse(input: &str, line: &str) -> Result<Self, ParseError> {
+        let (springs, groups) = line
+            .split_once(' ')
+            .ok_or_else(|| ParseError::new(input, line, "\"SPRINGS GROUPS\""))?;
+        let springs = springs
+            .chars()
+            .map(Spring::from_char)
+            .collect::<Option<Vec<_>>>()
+            .ok_or_else(|| ParseError::new(input, springs, "only ., # and ?"))?;
+        let groups = groups
+            .split(',')
+            .map(|group| match number(input, group)? {
+                0 => Err(ParseError::new(input, group, "a group of at least 1")),
+                size => Ok(size),
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Self { springs, groups })
+    }
+
+    /// The record five times over, with an unknown spring between the copies
+    fn unfold(&self) -> Record {
+        let mut springs = self.springs.clone();
+        for _ in 1..5 {
+            springs.push(Spring::Unknown);
+            springs.extend(&self.springs);
+        }
+        Record {
+            springs,
+            groups: self.groups.repeat(5),
+        }
+    }
+
+    fn arrangements(&self) -> u64 {
+        Arrangements::new(self).count(0, 0)
+    }
+
+    /// Every way to fill in the unknown springs, as the springs would read
+    fn list(&self) -> Vec<String> {
+        let mut arrangements = Arrangements::new(self);
+        let mut found = Vec::new();
+        let mut row = Vec::with_capacity(self.springs.len());
+        arrangements.list(0, 0, &mut row, &mut found);
+        found
+    }
+}
+
+/// Counts the ways to place the groups of a record, memoized on where the next
+/// spring and the next group are
+struct Arrangements<'a> {
+    record: &'a Record,
+    memo: Vec<Vec<Option<u64>>>,
+}
+
+impl<'a> Arrangements<'a> {
+    fn new(record: &'a Record) -> Self {
+        Self {
+            record,
+            memo: vec![vec![None; record.groups.len() + 1]; record.springs.len() + 1],
+        }
+    }
+
+    /// Whether the group `group` fits with its first spring at `at`, leaving it
+    /// either at the end or followed by a spring that can be operational
+    fn fits(&self, at: usize, group: usize) -> bool {
+        let springs = &self.record.springs;
+        let end = at + self.record.groups[group];
+        end <= springs.len()
+            && springs[at..end].iter().all(|&s| s != Spring::Operational)
+            && springs.get(end) != Some(&Spring::Damaged)
+    }
+
+    /// Arrangements of the springs from `at` on holding the groups from `group` on
+    fn count(&mut self, at: usize, group: usize) -> u64 {
+        let springs = &self.record.springs;
+        if at >= springs.len() {
+            return (group == self.record.groups.len()).into();
+        }
+        if let Some(count) = self.memo[at][group] {
+            return count;
+        }
+        let mut count = 0;
+        if springs[at] != Spring::Damaged {
+            count += self.count(at + 1, group);
+        }
+        if group < self.record.groups.len() && self.fits(at, group) {
+            // step over the operational spring after the group too
+            count += self.count(at + self.record.groups[group] + 1, group + 1);
+        }
+        self.memo[at][group] = Some(count);
+        count
+    }
+
+    /// Add the arrangements counted by `count(at, group)` to `found`, each after `row`.
+    /// Only branches with some arrangement are followed, so this takes time in
+    /// proportion to what it lists.
+    fn list(&mut self, at: usize, group: usize, row: &mut Vec<char>, found: &mut Vec<String>) {
+        if self.count(at, group) == 0 {
+            return;
+        }
+        let springs = &self.record.springs;
+        if at >= springs.len() {
+            found.push(row.iter().collect());
+            return;
+        }
+        let len = row.len();
+        if springs[at] != Spring::Damaged {
+            row.push('.');
+            self.list(at + 1, group, row, found);
+            row.truncate(len);
+        }
+        if group < self.record.groups.len() && self.fits(at, group) {
+            let size = self.record.groups[group];
+            row.extend(std::iter::repeat_n('#', size));
+            if at + size < springs.len() {
+                row.push('.');
+            }
+            self.list(at + size + 1, group + 1, row, found);
+            row.truncate(len);
+        }
+    }
+}
+
+/// The arrangements of each record, listed when there are only a few, for `aoc run --explain`.
+/// Part B explains the unfolded records.
+pub fn explain(input: &str, part: Part) -> Result<String, ParseError> {
+    let mut out = String::new();
+    for record in Puzzle::parse(input)? {
+        let record = match part {
+            Part::A => record,
+            Part::B => record.unfold(),
+        };
+        let springs = record
+            .springs
+            .iter()
+            .map(|s| s.to_char())
+            .collect::<String>();
+        let groups = record
+            .groups
+            .iter()
+            .map(|g| g.to_string())
+            .collect::<Vec<_>>();
+        let count = record.arrangements();
+        let plural = if count == 1 { "" } else { "s" };
+        let _ = write!(
+            out,
+            "{springs} {}: {count} arrangement{plural}",
+            groups.join(",")
+        );
+        if count > MAX_LISTED {
+            let _ = writeln!(out, ", too many to list");
+            continue;
+        }
+        let _ = writeln!(out);
+        for arrangement in record.list() {
+            let _ = writeln!(out, "  {arrangement}");
+        }
+    }
+    Ok(out)
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input = Vec<Record>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input
+            .lines()
+            .map(|line| Record::parse(input, line))
+            .collect()
+    }
+
+    fn part_a(records: &Self::Input) -> Answer {
+        records.iter().map(Record::arrangements).sum::<u64>().into()
+    }
+
+    fn part_b(records: &Self::Input) -> Answer {
+        records
+            .iter()
+            .map(|record| record.unfold().arrangements())
+            .sum::<u64>()
+            .into()
+    }
+}
+
+aoc_common::example_tests!();
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(line: &str) -> Record {
+        Record::parse(line, line).unwrap()
+    }
+
+    #[test]
+    fn test_arrangements() {
+        assert_eq!(record("???.### 1,1,3").arrangements(), 1);
+        assert_eq!(record("?###???????? 3,2,1").arrangements(), 10);
+        assert_eq!(record("?###???????? 3,2,1").unfold().arrangements(), 506250);
+        assert_eq!(record("# 2").arrangements(), 0);
+        assert_eq!(record("#.# 1").arrangements(), 0);
+    }
+
+    #[test]
+    fn test_list() {
+        assert_eq!(
+            record(".??..??...?##. 1,1,3").list(),
+            [
+                "..#...#...###.",
+                "..#..#....###.",
+                ".#....#...###.",
+                ".#...#....###.",
+            ]
+        );
+        assert_eq!(record("?? 1").list(), [".#", "#."]);
+    }
+
+    #[test]
+    fn test_explain() {
+        let input = "?###???????? 3,2,1\n????.######..#####. 1,6,5\n";
+        let explained = explain(input, Part::A).unwrap();
+        assert!(explained.starts_with("?###???????? 3,2,1: 10 arrangements\n"));
+        assert!(explained.contains("????.######..#####. 1,6,5: 4 arrangements\n"));
+        assert!(explained.contains("\n  #....######..#####.\n"));
+        let unfolded = explain(input, Part::B).unwrap();
+        assert!(unfolded.contains(": 506250 arrangements, too many to list\n"));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(Puzzle::parse("#?x 1\n").err().unwrap().token, "#?x");
+        assert_eq!(Puzzle::parse("#?. 1,0\n").err().unwrap().token, "0");
+        assert_eq!(Puzzle::parse("#?.\n").err().unwrap().token, "#?.");
+    }
+}
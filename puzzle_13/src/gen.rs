@@ -0,0 +1,137 @@
+//! Random notes: `size` patterns, each with exactly one line of reflection and exactly
+//! one smudge that makes a different line reflect.
+//!
+//! A pattern starts out reflected both across a row line and across a column line. Then
+//! one cell outside the rows the row line reflects, but inside the columns the column
+//! line reflects, gets flipped: the row reflection survives and the column one is left
+//! off by that one cell. Patterns where any other line comes out reflecting, or off by
+//! one, are thrown away, and half of the rest are turned on their side.
+
+use aoc_common::gen::{Rng, RngExt};
+
+pub fn generate<R: Rng>(rng: &mut R, size: usize) -> String {
+    (0..size)
+        .map(|_| pattern(rng))
+        .map(|cells| {
+            cells
+                .iter()
+                .map(|row| row.iter().collect::<String>() + "\n")
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn pattern<R: Rng>(rng: &mut R) -> Vec<Vec<char>> {
+    loop {
+        let (height, width) = (rng.random_range(5..=17), rng.random_range(5..=17));
+        // a row line that leaves some rows unreflected, for the smudge to go in
+        let row_line = loop {
+            let line = rng.random_range(1..height);
+            if 2 * line != height {
+                break line;
+            }
+        };
+        let col_line = rng.random_range(1..width);
+        let reflect = |line: usize, len: usize, i: usize| {
+            let overlap = line.min(len - line);
+            match i >= line && i < line + overlap {
+                true => 2 * line - 1 - i,
+                false => i,
+            }
+        };
+
+        let random = (0..height)
+            .map(|_| {
+                (0..width)
+                    .map(|_| if rng.random_bool(0.5) { '#' } else { '.' })
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        let mut cells = (0..height)
+            .map(|row| {
+                (0..width)
+                    .map(|col| {
+                        random[reflect(row_line, height, row)][reflect(col_line, width, col)]
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+
+        let (row_overlap, col_overlap) = (
+            row_line.min(height - row_line),
+            col_line.min(width - col_line),
+        );
+        let row = loop {
+            let row = rng.random_range(0..height);
+            if row + row_overlap < row_line || row >= row_line + row_overlap {
+                break row;
+            }
+        };
+        let col = rng.random_range(col_line - col_overlap..col_line + col_overlap);
+        cells[row][col] = if cells[row][col] == '#' { '.' } else { '#' };
+
+        if summary(&cells, 0) == [100 * row_line] && summary(&cells, 1) == [col_line] {
+            return match rng.random_bool(0.5) {
+                true => transpose(&cells),
+                false => cells,
+            };
+        }
+    }
+}
+
+fn transpose(cells: &[Vec<char>]) -> Vec<Vec<char>> {
+    (0..cells[0].len())
+        .map(|col| cells.iter().map(|row| row[col]).collect())
+        .collect()
+}
+
+/// The puzzle's number for every line where the reflection is off by `mismatches` cells:
+/// the columns to the left of it, or 100 times the rows above it
+fn summary(cells: &[Vec<char>], mismatches: usize) -> Vec<usize> {
+    let off_by = |rows: &[Vec<char>], line: usize| {
+        let overlap = line.min(rows.len() - line);
+        (0..overlap)
+            .map(|i| {
+                let (above, below) = (&rows[line - 1 - i], &rows[line + i]);
+                above.iter().zip(below).filter(|(a, b)| a != b).count()
+            })
+            .sum::<usize>()
+    };
+    let rows = (1..cells.len())
+        .filter(|&line| off_by(cells, line) == mismatches)
+        .map(|line| 100 * line);
+    let columns = transpose(cells);
+    let cols = (1..columns.len()).filter(|&line| off_by(&columns, line) == mismatches);
+    rows.chain(cols).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::gen::seeded;
+    use aoc_common::{Answer, Solution};
+
+    use super::*;
+    use crate::Puzzle;
+
+    #[test]
+    fn test_generate() {
+        for seed in 0..20 {
+            let mut rng = seeded(seed);
+            let patterns = (0..5).map(|_| pattern(&mut rng)).collect::<Vec<_>>();
+            let (mut a, mut b) = (0, 0);
+            for cells in &patterns {
+                let (exact, smudged) = (summary(cells, 0), summary(cells, 1));
+                assert_eq!((exact.len(), smudged.len()), (1, 1));
+                a += exact[0];
+                b += smudged[0];
+            }
+
+            let input = generate(&mut seeded(seed), 5);
+            let parsed = Puzzle::parse(&input).unwrap();
+            assert_eq!(parsed.len(), 5);
+            assert_eq!(Puzzle::part_a(&parsed), Answer::from(a), "{input}");
+            assert_eq!(Puzzle::part_b(&parsed), Answer::from(b), "{input}");
+        }
+    }
+}
@@ -0,0 +1,202 @@
+pub mod gen;
+
+use aoc_common::parse::blocks;
+use aoc_common::{Answer, ParseError, Solution};
+use aoc_grid::frames::{self, Frame, Line};
+use aoc_grid::{Coord, Grid};
+
+#[derive(Debug, Clone)]
+pub struct Pattern {
+    cells: Grid<char>,
+}
+
+impl Pattern {
+    fn parse(input: &str, lines: &[&str]) -> Result<Self, ParseError> {
+        let cells = Grid::parse_lines(
+            input,
+            lines.iter().copied(),
+            |c| "#.".contains(c).then_some(c),
+            "# or .",
+        )?;
+        Ok(Self { cells })
+    }
+
+    fn flip_at(&mut self, (row, col): (usize, usize)) {
+        let cell = &mut self.cells[Coord::new(row, col)];
+        *cell = match *cell {
+            '.' => '#',
+            '#' => '.',
+            _ => panic!("unexpected char in cells"),
+        }
+    }
+
+    fn transpose(&self) -> Pattern {
+        Pattern {
+            cells: self.cells.transpose(),
+        }
+    }
+
+    fn find_reflections(&self) -> Vec<usize> {
+        let mut reflections = Vec::new();
+        let cells = self.cells.rows().collect::<Vec<_>>();
+        for i in 1..cells.len() {
+            let (left, right) = cells.split_at(i);
+            let overlap = left.len().min(right.len());
+            // trim to the same size
+            let is_mirrored = (0..overlap).all(|i| left[left.len() - 1 - i] == right[i]);
+            if is_mirrored {
+                // dbg!(&left[left.len()-1-i], &right[i]);
+                // dbg!(left, right);
+                reflections.push(i);
+            }
+        }
+        reflections
+    }
+
+    #[allow(dead_code)]
+    fn print(&self) {
+        print!("{}", self.cells);
+    }
+
+    fn find_smudge(&self) -> Option<(usize, usize, usize)> {
+        let cells = self.cells.rows().collect::<Vec<_>>();
+        for fold_point in 1..cells.len() {
+            let (left, right) = cells.split_at(fold_point);
+            let overlap = left.len().min(right.len());
+            let distances: Vec<Vec<usize>> = (0..overlap)
+                .map(|i| distance(left[left.len() - 1 - i], right[i]))
+                .collect();
+            let mut total_dist: usize = 0;
+            let mut last_diff: (usize, usize, usize) = (0, 0, 0);
+            for (i, dvec) in distances.iter().enumerate() {
+                for (j, d) in dvec.iter().enumerate() {
+                    total_dist += *d;
+                    if *d == 1 {
+                        last_diff = (fold_point + i, j, fold_point);
+                    }
+                }
+            }
+            if total_dist == 1 {
+                // smudge is at this fold
+                return Some(last_diff);
+            }
+        }
+        None
+    }
+}
+
+fn distance(a: &[char], b: &[char]) -> Vec<usize> {
+    assert_eq!(a.len(), b.len());
+    let mut diff: Vec<usize> = Vec::with_capacity(a.len());
+    for i in 0..a.len() {
+        diff.push(match a[i] == b[i] {
+            true => 0,
+            false => 1,
+        })
+    }
+    diff
+}
+
+fn parse_input(input: &str) -> Result<Vec<Pattern>, ParseError> {
+    blocks(input)
+        .iter()
+        .map(|lines| Pattern::parse(input, lines))
+        .collect()
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input = Vec<Pattern>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_a(patterns: &Self::Input) -> Answer {
+        let mut total = 0;
+        for p in patterns {
+            // let horizontal_reflections = p.find_reflections();
+            // let veritical_reflections = p.transpose().find_reflections();
+            let columns = p.transpose().find_reflections();
+            let rows = p.find_reflections();
+            columns.iter().for_each(|f| total += f);
+            rows.iter().for_each(|f| total += 100 * f);
+            frames::emit(|| {
+                let lines = columns
+                    .iter()
+                    .map(|&f| Line::Column(f))
+                    .chain(rows.iter().map(|&f| Line::Row(f)));
+                lines.fold(Frame::new(&p.cells), Frame::line)
+            });
+        }
+        total.into()
+    }
+
+    fn part_b(patterns: &Self::Input) -> Answer {
+        let mut patterns = patterns.clone();
+        let mut total = 0;
+        for (pattern_idx, pattern) in patterns.iter_mut().enumerate() {
+            let mut transposed = pattern.transpose();
+            match transposed.find_smudge() {
+                Some((col, row, fold_point)) => {
+                    transposed.flip_at((col, row));
+                    pattern.flip_at((row, col));
+                    total += fold_point;
+                    frames::emit(|| {
+                        Frame::new(&pattern.cells)
+                            .highlight([Coord::new(row, col)])
+                            .line(Line::Column(fold_point))
+                    });
+                }
+                None => match pattern.find_smudge() {
+                    Some((row, col, fold_point)) => {
+                        let before = pattern.cells[Coord::new(row, col)];
+                        pattern.flip_at((row, col));
+                        transposed.flip_at((col, row));
+                        assert!(before != pattern.cells[Coord::new(row, col)]);
+                        assert_eq!(
+                            pattern.cells[Coord::new(row, col)],
+                            transposed.cells[Coord::new(col, row)]
+                        );
+                        total += 100 * fold_point;
+                        frames::emit(|| {
+                            Frame::new(&pattern.cells)
+                                .highlight([Coord::new(row, col)])
+                                .line(Line::Row(fold_point))
+                        });
+                    }
+                    None => return format!("no smudge in pattern {}", pattern_idx + 1).into(),
+                },
+            }
+            //  Ugh! it was super unclear in the description that they were looking for only
+            // the new reflections. Not the total of all reflections (as in part A) after fixing the smudge.
+            // pattern.print();
+            // transposed
+            //     .find_reflections()
+            //     .iter()
+            //     .for_each(|f| total += f);
+            // pattern
+            //     .find_reflections()
+            //     .iter()
+            //     .for_each(|f| total += 100 * f);
+        }
+        total.into()
+    }
+}
+
+aoc_common::example_tests!();
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_no_smudge() {
+        let patterns = Puzzle::parse("#.\n.#\n\n##\n..\n").unwrap();
+        assert_eq!(
+            Puzzle::part_b(&patterns),
+            Answer::from("no smudge in pattern 1")
+        );
+    }
+}
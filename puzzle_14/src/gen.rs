@@ -0,0 +1,40 @@
+//! Random platforms: a `size` by `size` grid with rounded rocks on a fifth of the cells
+//! and cube-shaped rocks on about one in seven.
+
+use aoc_common::gen::{Rng, RngExt};
+
+pub fn generate<R: Rng>(rng: &mut R, size: usize) -> String {
+    let mut input = String::new();
+    for _ in 0..size {
+        for _ in 0..size {
+            let roll = rng.random_range(0.0..1.0);
+            input.push(match roll {
+                _ if roll < 0.2 => 'O',
+                _ if roll < 0.35 => '#',
+                _ => '.',
+            });
+        }
+        input.push('\n');
+    }
+    input
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::gen::seeded;
+    use aoc_common::Solution;
+
+    use super::*;
+    use crate::Puzzle;
+
+    #[test]
+    fn test_generate() {
+        for seed in 0..10 {
+            let input = generate(&mut seeded(seed), 12);
+            let platform = Puzzle::parse(&input).unwrap();
+            assert_eq!(platform.grid.width(), 12);
+            Puzzle::part_a(&platform);
+            Puzzle::part_b(&platform);
+        }
+    }
+}
@@ -0,0 +1,205 @@
+pub mod gen;
+
+use aoc_common::simulate::{simulate, Simulation};
+use aoc_common::{Answer, ParseError, Solution};
+use aoc_grid::frames::{self, Frame};
+use aoc_grid::{Coord, Dir4, Grid};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Item {
+    Round,
+    Square,
+    Empty,
+}
+use Item::*;
+
+impl std::fmt::Display for Item {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let c = match self {
+            Empty => '.',
+            Round => 'O',
+            Square => '#',
+        };
+        write!(f, "{c}")
+    }
+}
+
+#[derive(Debug, Hash, Clone, PartialEq, Eq)]
+pub struct Platform {
+    grid: Grid<Item>,
+}
+
+impl Platform {
+    fn load(input: &str) -> Result<Self, ParseError> {
+        let grid = Grid::parse(
+            input,
+            |c| match c {
+                'O' => Some(Round),
+                '#' => Some(Square),
+                '.' => Some(Empty),
+                _ => None,
+            },
+            "O, # or .",
+        )?;
+        Ok(Self { grid })
+    }
+
+    fn get(&self, coord: Coord) -> Item {
+        self.grid[coord]
+    }
+
+    fn look(&self, from: Coord, dir: Dir4) -> Option<Item> {
+        self.grid.step(from, dir).map(|coord| self.get(coord))
+    }
+
+    fn swap_at(&mut self, src: Coord, dir: Dir4) {
+        let dst = self.grid.step(src, dir).expect("no room to move");
+        assert!(self.get(src) == Round);
+        assert!(self.get(dst) == Empty);
+        self.grid.swap(src, dst);
+    }
+
+    fn tilt(&mut self, dir: Dir4) {
+        loop {
+            let mut swaps = Vec::new();
+            for (coord, item) in self.grid.iter() {
+                if *item == Item::Round && self.look(coord, dir).is_some_and(|i| i == Item::Empty) {
+                    swaps.push((coord, dir));
+                }
+            }
+            for (coord, dir) in &swaps {
+                self.swap_at(*coord, *dir);
+            }
+            frames::emit(|| {
+                let moved = swaps
+                    .iter()
+                    .filter_map(|(coord, dir)| self.grid.step(*coord, *dir));
+                Frame::new(&self.grid)
+                    .highlight(moved)
+                    .caption(format!("tilting {dir:?}, {} rocks rolled", swaps.len()))
+            });
+
+            if swaps.is_empty() {
+                break;
+            }
+        }
+    }
+
+    fn total_load(&self) -> i32 {
+        let mut total = 0;
+        let n_rows = self.grid.height();
+        for (coord, item) in self.grid.iter() {
+            if *item == Item::Round {
+                total += n_rows - coord.row;
+            }
+        }
+        total as i32
+    }
+}
+
+impl std::fmt::Display for Platform {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.grid)
+    }
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input = Platform;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Platform::load(input)
+    }
+
+    fn part_a(platform: &Self::Input) -> Answer {
+        tilt_north_load(platform.clone()).into()
+    }
+
+    fn part_b(platform: &Self::Input) -> Answer {
+        spin_cycle_load(platform.clone()).into()
+    }
+}
+
+fn tilt_north_load(mut platform: Platform) -> i32 {
+    loop {
+        let mut changes = 0;
+        let mut swap_locs = Vec::new();
+        for this_loc in platform.grid.coords() {
+            if let Some(dest_loc) = platform.grid.step(this_loc, Dir4::North) {
+                if platform.get(this_loc) == Item::Round && platform.get(dest_loc) == Empty {
+                    swap_locs.push(this_loc);
+                    changes += 1;
+                }
+            }
+        }
+        for &loc in &swap_locs {
+            platform.swap_at(loc, Dir4::North);
+        }
+        frames::emit(|| {
+            let moved = swap_locs
+                .iter()
+                .filter_map(|loc| platform.grid.step(*loc, Dir4::North));
+            let caption = match changes {
+                0 => "tilted north".to_string(),
+                _ => format!("tilting North, {changes} rocks rolled"),
+            };
+            Frame::new(&platform.grid).highlight(moved).caption(caption)
+        });
+        if changes == 0 {
+            break;
+        }
+    }
+    platform.total_load()
+}
+
+/// The platforms after each of `n` spin cycles, up to where they start repeating
+fn spin_cycles(platform: Platform, n: usize) -> Simulation<Platform> {
+    fn rotate(input: &Platform) -> Platform {
+        let mut output = input.clone();
+        for dir in [Dir4::North, Dir4::West, Dir4::South, Dir4::East] {
+            output.tilt(dir);
+        }
+        output
+    }
+
+    simulate(platform, rotate, n)
+}
+
+fn spin_cycle_load(platform: Platform) -> i32 {
+    spin_cycles(platform, 1000000000).last().total_load()
+}
+
+aoc_common::example_tests!();
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::simulate::Cycle;
+
+    use super::*;
+
+    #[test]
+    fn test_spin_cycles() {
+        let platform = Puzzle::parse(include_str!("../examples/01.txt")).unwrap();
+        let simulation = spin_cycles(platform, 1000000000);
+        let loads = simulation
+            .history()
+            .iter()
+            .map(Platform::total_load)
+            .collect::<Vec<_>>();
+        assert_eq!(simulation.cycle(), Some(Cycle { start: 3, len: 7 }));
+        // the loads the puzzle lists after each cycle, after the untilted 104
+        assert_eq!(loads, [104, 87, 69, 69, 69, 65, 64, 65, 63, 68]);
+        assert_eq!(simulation.last().total_load(), 64);
+    }
+
+    #[test]
+    fn test_swap() {
+        let mut platform = Platform {
+            grid: Grid::from_vec(1, 2, vec![Item::Empty, Item::Round]),
+        };
+        platform.swap_at(Coord { row: 1, col: 0 }, Dir4::North);
+        assert_eq!(platform.get(Coord { row: 0, col: 0 }), Item::Round);
+        assert_eq!(platform.get(Coord { row: 1, col: 0 }), Item::Empty);
+    }
+}
@@ -0,0 +1,14 @@
+//! Random inputs for `aoc gen`: `size` lines of random letters, until this day's format
+//! is known.
+
+use aoc_common::gen::{Rng, RngExt};
+
+pub fn generate<R: Rng>(rng: &mut R, size: usize) -> String {
+    let mut input = String::new();
+    for _ in 0..size {
+        let len = rng.random_range(1..=20);
+        input.extend((0..len).map(|_| rng.random_range('a'..='z')));
+        input.push('\n');
+    }
+    input
+}
@@ -0,0 +1,23 @@
+pub mod gen;
+
+use aoc_common::{Answer, ParseError, Solution};
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input = Vec<String>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok(input.lines().map(|line| line.to_string()).collect())
+    }
+
+    fn part_a(_input: &Self::Input) -> Answer {
+        0.into()
+    }
+
+    fn part_b(_input: &Self::Input) -> Answer {
+        0.into()
+    }
+}
+
+aoc_common::example_tests!();